@@ -1,7 +1,9 @@
 use super::client::*;
 use super::errors::*;
+use super::pagination::*;
 use super::rest_model::*;
 use super::util::*;
+use futures::stream::Stream;
 use serde_json::from_str;
 use std::collections::BTreeMap;
 
@@ -13,6 +15,13 @@ static API_V3_ORDER: &str = "/api/v3/order";
 /// Endpoint for test orders.
 /// Orders issued to this endpoint are validated, but not sent into the matching engine.
 static API_V3_ORDER_TEST: &str = "/api/v3/order/test";
+static API_V3_ORDER_CANCEL_REPLACE: &str = "/api/v3/order/cancelReplace";
+static API_V3_ORDER_AMEND_KEEP_PRIORITY: &str = "/api/v3/order/amend/keepPriority";
+static API_V3_ORDER_OCO: &str = "/api/v3/order/oco";
+static API_V3_ORDER_LIST_OCO: &str = "/api/v3/orderList/oco";
+static API_V3_ORDER_LIST: &str = "/api/v3/orderList";
+static API_V3_ALL_ORDER_LIST: &str = "/api/v3/allOrderList";
+static API_V3_OPEN_ORDER_LIST: &str = "/api/v3/openOrderList";
 
 #[derive(Clone)]
 pub struct Account {
@@ -88,6 +97,226 @@ pub struct OrdersQuery {
     pub recv_window: Option<u64>,
 }
 
+/// Trades Query
+/// perform a query on the trades of the account for a single symbol
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TradesQuery {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Cannot be sent with start_time or end_time
+    pub from_id: Option<u64>,
+    /// Default 500 max 1000
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Cancel Replace Request
+/// cancel an existing order and place a new one in a single request
+/// either cancel_order_id or cancel_orig_client_order_id must be set
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub cancel_replace_mode: CancelReplaceMode,
+    pub time_in_force: Option<TimeInForce>,
+    pub quantity: Option<f64>,
+    pub quote_order_qty: Option<f64>,
+    pub price: Option<f64>,
+    /// Used to uniquely identify this cancel. Automatically generated by default.
+    pub cancel_new_client_order_id: Option<String>,
+    pub cancel_orig_client_order_id: Option<String>,
+    pub cancel_order_id: Option<u64>,
+    /// A unique id for the new order, automatically generated if not sent.
+    pub new_client_order_id: Option<String>,
+    pub stop_price: Option<f64>,
+    pub trailing_delta: Option<u64>,
+    pub iceberg_qty: Option<f64>,
+    /// Set the response json of the new order, market and limit default to full others to ack.
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// Only cancel the order if it is in the given status
+    pub cancel_restrictions: Option<CancelRestrictions>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+impl Default for CancelReplaceRequest {
+    fn default() -> Self {
+        Self {
+            symbol: String::new(),
+            side: OrderSide::default(),
+            order_type: OrderType::default(),
+            cancel_replace_mode: CancelReplaceMode::StopOnFailure,
+            time_in_force: None,
+            quantity: None,
+            quote_order_qty: None,
+            price: None,
+            cancel_new_client_order_id: None,
+            cancel_orig_client_order_id: None,
+            cancel_order_id: None,
+            new_client_order_id: None,
+            stop_price: None,
+            trailing_delta: None,
+            iceberg_qty: None,
+            new_order_resp_type: None,
+            cancel_restrictions: None,
+            recv_window: None,
+        }
+    }
+}
+
+/// Order Amend Request
+/// reduce the quantity of an existing order, the order keeps its priority in the book
+/// either order_id or orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    /// A new id for the amended order
+    pub new_client_order_id: Option<String>,
+    /// Must be greater than 0 and less than the order's quantity
+    pub new_qty: f64,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// OCO Order Request
+/// place a limit order and a stop loss (limit) order, when one of them executes the other is canceled
+/// the limit price must be above the last price and the stop price below it for a sell, the other way around for a buy
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OCOOrderRequest {
+    pub symbol: String,
+    /// A unique id for the order list, automatically generated if not sent.
+    pub list_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub quantity: f64,
+    /// A unique id for the limit order, automatically generated if not sent.
+    pub limit_client_order_id: Option<String>,
+    pub price: f64,
+    pub limit_iceberg_qty: Option<f64>,
+    /// A unique id for the stop loss order, automatically generated if not sent.
+    pub stop_client_order_id: Option<String>,
+    pub stop_price: f64,
+    /// When set, the stop order is a stop loss limit order and stop_limit_time_in_force is required
+    pub stop_limit_price: Option<f64>,
+    pub stop_iceberg_qty: Option<f64>,
+    pub stop_limit_time_in_force: Option<TimeInForce>,
+    /// Set the response json, default to ack.
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Order List OCO Request
+/// place an OCO through the newer orderList endpoint, described as an above and a below leg
+/// relative to the last price
+/// the above leg is one of StopLoss, StopLossLimit, LimitMaker, TakeProfit, TakeProfitLimit
+/// the below leg is one of StopLoss, StopLossLimit, TakeProfit, TakeProfitLimit
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListOCORequest {
+    pub symbol: String,
+    /// A unique id for the order list, automatically generated if not sent.
+    pub list_client_order_id: Option<String>,
+    pub side: OrderSide,
+    pub quantity: f64,
+    pub above_type: OrderType,
+    pub above_client_order_id: Option<String>,
+    pub above_iceberg_qty: Option<f64>,
+    pub above_price: Option<f64>,
+    pub above_stop_price: Option<f64>,
+    pub above_trailing_delta: Option<u64>,
+    pub above_time_in_force: Option<TimeInForce>,
+    pub below_type: OrderType,
+    pub below_client_order_id: Option<String>,
+    pub below_iceberg_qty: Option<f64>,
+    pub below_price: Option<f64>,
+    pub below_stop_price: Option<f64>,
+    pub below_trailing_delta: Option<u64>,
+    pub below_time_in_force: Option<TimeInForce>,
+    /// Set the response json, default to ack.
+    pub new_order_resp_type: Option<OrderResponse>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Order List Cancellation Request
+/// cancel every order of an order list
+/// either order_list_id or list_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListCancellation {
+    pub symbol: String,
+    pub order_list_id: Option<u64>,
+    pub list_client_order_id: Option<String>,
+    /// Used to uniquely identify this cancel. Automatically generated by default.
+    pub new_client_order_id: Option<String>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Order List Status Request
+/// either order_list_id or orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListStatusRequest {
+    pub order_list_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Order Lists Query
+/// perform a query on all order lists for the account
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderListsQuery {
+    /// Cannot be sent with start_time or end_time
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 500 max 1000
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Page limits of the allOrders and myTrades endpoints
+static HISTORY_PAGE_LIMITS: PageLimits = PageLimits {
+    page_size: 1000,
+    max_window: 24 * 60 * 60 * 1000,
+    pause: DEFAULT_PAGE_PAUSE,
+};
+
+impl Paged for Order {
+    fn page_id(&self) -> u64 {
+        self.order_id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Paged for TradeHistory {
+    fn page_id(&self) -> u64 {
+        self.id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+}
+
 impl Account {
     /// General account information
     /// # Examples
@@ -397,4 +626,294 @@ impl Account {
 
         Ok(trade_history)
     }
+
+    /// Trades for a single symbol matching the query
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let query = TradesQuery {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     from_id: Some(1),
+    ///     ..TradesQuery::default()
+    /// };
+    /// let trades = tokio_test::block_on(account.get_trades(query));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_trades(&self, query: TradesQuery) -> Result<Vec<TradeHistory>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(query, recv_window)?;
+        let data = self.client.get_signed(API_V3_MYTRADES, &request).await?;
+        let trade_history: Vec<TradeHistory> = from_str(data.as_str())?;
+
+        Ok(trade_history)
+    }
+
+    /// Every order of a symbol, paginated transparently from an order id or through a time range
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*, pagination::*};
+    /// use futures::StreamExt;
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let orders = account.all_orders_stream("BTCUSDT", HistoryCursor::FromId(0));
+    /// let orders: Vec<_> = tokio_test::block_on(orders.collect());
+    /// ```
+    pub fn all_orders_stream<S>(
+        &self,
+        symbol: S,
+        cursor: HistoryCursor,
+    ) -> impl Stream<Item = Result<Order>>
+    where
+        S: Into<String>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        paginate(cursor, HISTORY_PAGE_LIMITS, move |page: PageRequest| {
+            let account = account.clone();
+            let query = OrdersQuery {
+                symbol: symbol.clone(),
+                order_id: page.from_id,
+                start_time: page.start_time,
+                end_time: page.end_time,
+                limit: Some(HISTORY_PAGE_LIMITS.page_size as u32),
+                recv_window: None,
+            };
+            async move { account.get_all_orders(query).await }
+        })
+    }
+
+    /// Every trade of a symbol, paginated transparently from a trade id or through a time range
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*, pagination::*};
+    /// use futures::StreamExt;
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let range = HistoryCursor::Between { start_time: 1640995200000, end_time: 1643673600000 };
+    /// let trades = account.trades_stream("BTCUSDT", range);
+    /// let trades: Vec<_> = tokio_test::block_on(trades.collect());
+    /// ```
+    pub fn trades_stream<S>(
+        &self,
+        symbol: S,
+        cursor: HistoryCursor,
+    ) -> impl Stream<Item = Result<TradeHistory>>
+    where
+        S: Into<String>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        paginate(cursor, HISTORY_PAGE_LIMITS, move |page: PageRequest| {
+            let account = account.clone();
+            let query = TradesQuery {
+                symbol: symbol.clone(),
+                order_id: None,
+                start_time: page.start_time,
+                end_time: page.end_time,
+                from_id: page.from_id,
+                limit: Some(HISTORY_PAGE_LIMITS.page_size as u32),
+                recv_window: None,
+            };
+            async move { account.get_trades(query).await }
+        })
+    }
+
+    /// Place an OCO order
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*, rest_model::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let oco = OCOOrderRequest {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     side: OrderSide::Sell,
+    ///     quantity: 0.01,
+    ///     price: 45000.0,
+    ///     stop_price: 35000.0,
+    ///     stop_limit_price: Some(34900.0),
+    ///     stop_limit_time_in_force: Some(TimeInForce::GTC),
+    ///     ..OCOOrderRequest::default()
+    /// };
+    /// let order_list = tokio_test::block_on(account.place_oco_order(oco));
+    /// assert!(order_list.is_ok(), "{:?}", order_list);
+    /// ```
+    pub async fn place_oco_order(&self, order: OCOOrderRequest) -> Result<OrderList> {
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .post_signed_p(API_V3_ORDER_OCO, order, recv_window)
+            .await
+    }
+
+    /// Place an OCO order through the order list endpoint
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*, rest_model::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let oco = OrderListOCORequest {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     side: OrderSide::Sell,
+    ///     quantity: 0.01,
+    ///     above_type: OrderType::LimitMaker,
+    ///     above_price: Some(45000.0),
+    ///     below_type: OrderType::StopLoss,
+    ///     below_stop_price: Some(35000.0),
+    ///     ..OrderListOCORequest::default()
+    /// };
+    /// let order_list = tokio_test::block_on(account.place_order_list_oco(oco));
+    /// assert!(order_list.is_ok(), "{:?}", order_list);
+    /// ```
+    pub async fn place_order_list_oco(&self, order: OrderListOCORequest) -> Result<OrderList> {
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .post_signed_p(API_V3_ORDER_LIST_OCO, order, recv_window)
+            .await
+    }
+
+    /// Cancel an entire order list
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let query = OrderListCancellation {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     order_list_id: Some(1),
+    ///     ..OrderListCancellation::default()
+    /// };
+    /// let canceled = tokio_test::block_on(account.cancel_order_list(query));
+    /// assert!(canceled.is_ok(), "{:?}", canceled);
+    /// ```
+    pub async fn cancel_order_list(&self, o: OrderListCancellation) -> Result<OrderList> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .delete_signed_p(API_V3_ORDER_LIST, o, recv_window)
+            .await
+    }
+
+    /// Check an order list's status
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let query = OrderListStatusRequest {
+    ///     order_list_id: Some(1),
+    ///     ..OrderListStatusRequest::default()
+    /// };
+    /// let order_list = tokio_test::block_on(account.order_list_status(query));
+    /// assert!(order_list.is_ok(), "{:?}", order_list);
+    /// ```
+    pub async fn order_list_status(&self, osr: OrderListStatusRequest) -> Result<OrderList> {
+        let recv_window = osr.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(API_V3_ORDER_LIST, Some(osr), recv_window)
+            .await
+    }
+
+    /// All order lists of the account
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let order_lists = tokio_test::block_on(account.get_all_order_lists(OrderListsQuery::default()));
+    /// assert!(order_lists.is_ok(), "{:?}", order_lists);
+    /// ```
+    pub async fn get_all_order_lists(&self, query: OrderListsQuery) -> Result<Vec<OrderList>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(API_V3_ALL_ORDER_LIST, Some(query), recv_window)
+            .await
+    }
+
+    /// Currently open order lists of the account
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let order_lists = tokio_test::block_on(account.get_open_order_lists());
+    /// assert!(order_lists.is_ok(), "{:?}", order_lists);
+    /// ```
+    pub async fn get_open_order_lists(&self) -> Result<Vec<OrderList>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        let data = self
+            .client
+            .get_signed(API_V3_OPEN_ORDER_LIST, &request)
+            .await?;
+        let order_lists: Vec<OrderList> = from_str(data.as_str())?;
+
+        Ok(order_lists)
+    }
+
+    /// Cancel an existing order and place a new one atomically
+    ///
+    /// When either leg fails, the error is `Error::CancelReplaceFailed` and carries the
+    /// outcome of both legs, so that a successful cancel or new order is never lost.
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*, rest_model::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let replace = CancelReplaceRequest {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     side: OrderSide::Buy,
+    ///     order_type: OrderType::Limit,
+    ///     cancel_replace_mode: CancelReplaceMode::StopOnFailure,
+    ///     time_in_force: Some(TimeInForce::GTC),
+    ///     quantity: Some(0.01),
+    ///     price: Some(30000.0),
+    ///     cancel_order_id: Some(1),
+    ///     ..CancelReplaceRequest::default()
+    /// };
+    /// let response = tokio_test::block_on(account.cancel_replace_order(replace));
+    /// assert!(response.is_ok(), "{:?}", response);
+    /// ```
+    pub async fn cancel_replace_order(
+        &self,
+        order: CancelReplaceRequest,
+    ) -> Result<CancelReplaceResponse> {
+        let recv_window = order.recv_window.unwrap_or(self.recv_window);
+        let request = build_signed_request_p(order, recv_window)?;
+        match self
+            .client
+            .post_signed(API_V3_ORDER_CANCEL_REPLACE, &request)
+            .await
+        {
+            Ok(data) => Ok(from_str(data.as_str())?),
+            Err(Error::BinanceError {
+                response:
+                    BinanceContentError {
+                        code,
+                        msg,
+                        data: Some(data),
+                    },
+            }) => {
+                let result: CancelReplaceResponse = serde_json::from_value(data)?;
+                Err(Error::CancelReplaceFailed {
+                    code,
+                    msg,
+                    result: Box::new(result),
+                })
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Reduce the quantity of an existing order while keeping its priority in the book
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, account::*, config::*};
+    /// let account: Account = Binance::new_with_env(&Config::testnet());
+    /// let amend = OrderAmendRequest {
+    ///     symbol: "BTCUSDT".to_string(),
+    ///     order_id: Some(1),
+    ///     new_qty: 0.005,
+    ///     ..OrderAmendRequest::default()
+    /// };
+    /// let amended = tokio_test::block_on(account.amend_order_keep_priority(amend));
+    /// assert!(amended.is_ok(), "{:?}", amended);
+    /// ```
+    pub async fn amend_order_keep_priority(
+        &self,
+        amend: OrderAmendRequest,
+    ) -> Result<OrderAmendment> {
+        let recv_window = amend.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .put_signed_p(API_V3_ORDER_AMEND_KEEP_PRIORITY, amend, recv_window)
+            .await
+    }
 }
@@ -5,7 +5,9 @@ use super::general::*;
 use super::margin::Margin;
 use super::market::*;
 use super::savings::Savings;
+use super::sub_account::SubAccount;
 use super::userstream::*;
+use super::ws_api::WsApi;
 
 pub trait Binance: Sized {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
@@ -66,6 +68,19 @@ impl Binance for Savings {
     }
 }
 
+impl Binance for SubAccount {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> Self {
+        Self {
+            client: Client::new(api_key, secret_key, config.rest_api_endpoint.clone()),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
 impl Binance for Market {
     fn new_with_config(
         api_key: Option<String>,
@@ -104,3 +119,13 @@ impl Binance for Margin {
         }
     }
 }
+
+impl Binance for WsApi {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> WsApi {
+        WsApi::new_with_options(api_key, secret_key, config.clone())
+    }
+}
@@ -2,6 +2,8 @@
 pub struct Config {
     pub rest_api_endpoint: String,
     pub ws_endpoint: String,
+    /// Endpoint of the request/response websocket API
+    pub ws_api_endpoint: String,
     pub recv_window: u64,
 }
 
@@ -16,6 +18,7 @@ impl Config {
         Config {
             rest_api_endpoint: "https://api.binance.com".into(),
             ws_endpoint: "wss://stream.binance.com:9443".into(),
+            ws_api_endpoint: "wss://ws-api.binance.com:443/ws-api/v3".into(),
             recv_window: 5000,
         }
     }
@@ -30,6 +33,7 @@ impl Config {
         Config::default()
             .set_rest_api_endpoint("https://testnet.binance.vision")
             .set_ws_endpoint("wss://testnet.binance.vision")
+            .set_ws_api_endpoint("wss://testnet.binance.vision/ws-api/v3")
     }
 
     pub fn set_rest_api_endpoint<T: Into<String>>(mut self, rest_api_endpoint: T) -> Self {
@@ -42,6 +46,11 @@ impl Config {
         self
     }
 
+    pub fn set_ws_api_endpoint<T: Into<String>>(mut self, ws_api_endpoint: T) -> Self {
+        self.ws_api_endpoint = ws_api_endpoint.into();
+        self
+    }
+
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
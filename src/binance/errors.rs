@@ -1,3 +1,4 @@
+use super::rest_model::CancelReplaceResponse;
 use super::ws_model::WebsocketEvent;
 use thiserror::Error;
 
@@ -6,6 +7,9 @@ use thiserror::Error;
 pub struct BinanceContentError {
     pub code: i16,
     pub msg: String,
+    /// Details sent along some errors, such as the outcome of each leg of a failed cancel-replace
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
 }
 
 /// First errors are technical errors
@@ -41,12 +45,23 @@ pub enum Error {
         #[from]
         response: BinanceContentError,
     },
+    #[error("cancel replace failed, code: {code}, msg: {msg}")]
+    CancelReplaceFailed {
+        code: i16,
+        msg: String,
+        /// Which of the cancel and the new order succeeded
+        result: Box<CancelReplaceResponse>,
+    },
     #[error("invalid listen key : {0}")]
     InvalidListenKey(String),
     #[error("unknown symbol {0}")]
     UnknownSymbol(String),
     #[error("{msg}")]
     InvalidOrderError { msg: String },
+    #[error("invalid withdrawal: {0}")]
+    InvalidWithdrawal(String),
+    #[error("invalid transfer: {0}")]
+    InvalidTransfer(String),
     #[error("invalid price")]
     InvalidPrice,
     #[error("invalid period {0}")]
@@ -66,4 +81,16 @@ pub mod error_messages {
     pub const INVALID_PRICE: &str = "Invalid price.";
 }
 
+impl From<crate::binance::klines::ZeroInterval> for Error {
+    fn from(_: crate::binance::klines::ZeroInterval) -> Self {
+        Error::InvalidPeriod("0".to_string())
+    }
+}
+
+impl From<crate::binance::pagination::PageOverflow> for Error {
+    fn from(e: crate::binance::pagination::PageOverflow) -> Self {
+        Error::Msg(e.to_string())
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
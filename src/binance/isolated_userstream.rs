@@ -0,0 +1,214 @@
+/*!
+User data streams of every isolated margin pair of an account.
+
+Isolated margin has one user data stream per pair, each with its own listen key. The
+multiplexer below opens the streams of all the enabled pairs of the account, keeps their listen
+keys alive together, and tells which pair an event of the combined stream comes from.
+
+```rust,no_run
+use std::sync::atomic::AtomicBool;
+use exrs::binance::{api::*, config::*, isolated_userstream::*, margin::*, websockets::*, ws_model::*};
+
+let margin: Margin = Binance::new_with_env(&Config::default());
+let running = AtomicBool::new(true);
+let result = actix_rt::System::new().block_on(async move {
+    let mut streams = IsolatedUserStreams::new(margin);
+    streams.start().await?;
+    let (tx, mut rx) = local_channel::mpsc::channel::<CombinedStreamEvent<WebsocketEvent>>();
+    let mut web_socket = WebSockets::new(tx);
+    web_socket.connect_multiple_streams(&streams.listen_keys()).await?;
+    actix_rt::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            let (listen_key, _) = event.parse_stream();
+            println!("{}: {:?}", listen_key, event.data);
+        }
+    });
+    let (events, renewed) = futures::join!(
+        web_socket.event_loop(&running),
+        streams.keep_alive_loop(&running)
+    );
+    events?;
+    println!("listen keys renewed for {:?}", renewed?);
+    streams.close().await
+});
+assert!(result.is_ok(), "{:?}", result);
+```
+*/
+
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+use log::debug;
+
+use super::errors::*;
+use super::margin::Margin;
+
+/// Listen keys expire after 60 minutes without keep alive
+pub static KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(30 * 60);
+
+/// How often the keep alive loop checks whether it should stop
+static RUNNING_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+/// User data streams of the isolated margin pairs of an account
+pub struct IsolatedUserStreams {
+    margin: Margin,
+    /// Listen key of every isolated pair, by symbol
+    listen_keys: BTreeMap<String, String>,
+}
+
+impl IsolatedUserStreams {
+    pub fn new(margin: Margin) -> Self {
+        IsolatedUserStreams {
+            margin,
+            listen_keys: BTreeMap::new(),
+        }
+    }
+
+    /// Open the streams of every enabled isolated pair of the account and close those of the
+    /// pairs which are not anymore, returns whether the streams changed
+    pub async fn start(&mut self) -> Result<bool> {
+        let details = self.margin.isolated_details(None).await?;
+        let symbols: Vec<String> = details
+            .assets
+            .into_iter()
+            .filter(|pair| pair.enabled)
+            .map(|pair| pair.symbol)
+            .collect();
+        self.start_symbols(&symbols).await
+    }
+
+    /// Open the streams of the given isolated pairs, and close those of the other pairs, returns
+    /// whether the streams changed
+    pub async fn start_symbols(&mut self, symbols: &[String]) -> Result<bool> {
+        let (closed, opened) = diff_symbols(&self.listen_keys, symbols);
+        for symbol in &closed {
+            if let Some(listen_key) = self.listen_keys.remove(symbol) {
+                self.margin.close_isolated(symbol, &listen_key).await?;
+            }
+        }
+        for symbol in &opened {
+            let stream = self.margin.start_isolated(symbol).await?;
+            self.listen_keys.insert(symbol.clone(), stream.listen_key);
+        }
+        Ok(!closed.is_empty() || !opened.is_empty())
+    }
+
+    /// Listen keys of all the streams, to connect to them as a combined stream
+    pub fn listen_keys(&self) -> Vec<String> {
+        self.listen_keys.values().cloned().collect()
+    }
+
+    /// Symbol of the isolated pair a listen key belongs to
+    pub fn symbol(&self, listen_key: &str) -> Option<&str> {
+        self.listen_keys
+            .iter()
+            .find(|(_, key)| key.as_str() == listen_key)
+            .map(|(symbol, _)| symbol.as_str())
+    }
+
+    /// Keep the listen keys of all the streams alive, the streams which could not be are
+    /// started again, returns the symbols whose listen key changed, the combined stream must
+    /// then be connected to again
+    pub async fn keep_alive(&mut self) -> Result<Vec<String>> {
+        let mut renewed = vec![];
+        for (symbol, listen_key) in self.listen_keys.iter_mut() {
+            match self.margin.keep_alive_isolated(symbol, listen_key).await {
+                Ok(_) => continue,
+                Err(e) => debug!("keep alive of the {} stream failed - {:?}", symbol, e),
+            }
+            let stream = self.margin.start_isolated(symbol).await?;
+            if stream.listen_key != *listen_key {
+                *listen_key = stream.listen_key;
+                renewed.push(symbol.clone());
+            }
+        }
+        Ok(renewed)
+    }
+
+    /// Keep all the streams alive every `KEEP_ALIVE_INTERVAL` while `running`, until a listen key
+    /// changes, returns the symbols whose listen key changed
+    pub async fn keep_alive_loop(&mut self, running: &AtomicBool) -> Result<Vec<String>> {
+        let mut last_keep_alive = Instant::now();
+        while running.load(Ordering::Relaxed) {
+            tokio::time::sleep(RUNNING_CHECK_INTERVAL).await;
+            if last_keep_alive.elapsed() < KEEP_ALIVE_INTERVAL {
+                continue;
+            }
+            last_keep_alive = Instant::now();
+            let renewed = self.keep_alive().await?;
+            if !renewed.is_empty() {
+                return Ok(renewed);
+            }
+        }
+        Ok(vec![])
+    }
+
+    /// Close all the streams
+    pub async fn close(&mut self) -> Result<()> {
+        let listen_keys = std::mem::take(&mut self.listen_keys);
+        for (symbol, listen_key) in listen_keys {
+            self.margin.close_isolated(&symbol, &listen_key).await?;
+        }
+        Ok(())
+    }
+}
+
+/// Symbols whose stream must be closed, and those whose stream must be opened, to go from the
+/// streams of `listen_keys` to those of `symbols`
+fn diff_symbols(
+    listen_keys: &BTreeMap<String, String>,
+    symbols: &[String],
+) -> (Vec<String>, Vec<String>) {
+    let closed = listen_keys
+        .keys()
+        .filter(|symbol| !symbols.contains(symbol))
+        .cloned()
+        .collect();
+    let mut opened: Vec<String> = vec![];
+    for symbol in symbols {
+        if !listen_keys.contains_key(symbol) && !opened.contains(symbol) {
+            opened.push(symbol.clone());
+        }
+    }
+    (closed, opened)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::api::Binance;
+
+    #[test]
+    fn finds_pairs_of_listen_keys() {
+        let mut streams = IsolatedUserStreams::new(Margin::new(None, None));
+        for (symbol, listen_key) in [("BTCUSDT", "T3ee22BIYuWq"), ("ETHBTC", "pqia91ma19a5")] {
+            streams
+                .listen_keys
+                .insert(symbol.to_string(), listen_key.to_string());
+        }
+        assert_eq!(streams.listen_keys(), vec!["T3ee22BIYuWq", "pqia91ma19a5"]);
+        assert_eq!(streams.symbol("pqia91ma19a5"), Some("ETHBTC"));
+        assert_eq!(streams.symbol("unknown"), None);
+    }
+
+    #[test]
+    fn diffs_streams_to_open_and_close() {
+        let mut listen_keys = BTreeMap::new();
+        for (symbol, listen_key) in [("BTCUSDT", "T3ee22BIYuWq"), ("ETHBTC", "pqia91ma19a5")] {
+            listen_keys.insert(symbol.to_string(), listen_key.to_string());
+        }
+        let symbols: Vec<String> = ["ETHBTC", "BNBUSDT", "BNBUSDT"]
+            .iter()
+            .map(|symbol| symbol.to_string())
+            .collect();
+        let (closed, opened) = diff_symbols(&listen_keys, &symbols);
+        assert_eq!(closed, vec!["BTCUSDT"]);
+        assert_eq!(opened, vec!["BNBUSDT"]);
+
+        let (closed, opened) = diff_symbols(&listen_keys, &["BTCUSDT".into(), "ETHBTC".into()]);
+        assert!(closed.is_empty() && opened.is_empty());
+        let (closed, opened) = diff_symbols(&BTreeMap::new(), &[]);
+        assert!(closed.is_empty() && opened.is_empty());
+    }
+}
@@ -0,0 +1,192 @@
+/*!
+Chunked download of historical klines.
+
+Kline endpoints return a bounded number of candles per call. A download splits the requested
+time range in request-sized windows, fetches them concurrently while spacing requests to stay
+under the rate limit, merges the windows back in order and reports the candles the exchange did
+not return.
+*/
+
+use std::future::Future;
+use std::time::Duration;
+
+use futures::stream::{self, StreamExt, TryStreamExt};
+use thiserror::Error;
+use tokio::time::Instant;
+
+static DAY_MILLIS: u64 = 24 * 60 * 60 * 1000;
+static WEEK_MILLIS: u64 = 7 * DAY_MILLIS;
+/// Weekly klines open on Monday, the Unix epoch was a Thursday
+static WEEK_OFFSET_MILLIS: u64 = 4 * DAY_MILLIS;
+
+/// A download was requested with an interval of zero
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("kline interval must not be zero")]
+pub struct ZeroInterval;
+
+/// A candle that can be positioned in a time range
+pub trait Candle {
+    /// Open time in ms
+    fn open_time(&self) -> u64;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct DownloadOptions {
+    /// Number of candles requested per call
+    pub chunk_size: u64,
+    /// Number of requests in flight at once
+    pub concurrency: usize,
+    /// Minimum delay between the start of two requests
+    pub spacing: Duration,
+}
+
+impl Default for DownloadOptions {
+    fn default() -> Self {
+        Self {
+            chunk_size: 1000,
+            concurrency: 4,
+            spacing: Duration::from_millis(100),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct KlineDownload<T> {
+    /// Candles sorted by open time, without duplicates
+    pub klines: Vec<T>,
+    /// Open times in the requested range for which no candle was returned
+    pub missing: Vec<u64>,
+}
+
+/// Duration of a kline interval ("1m", "4h", "1w"...) in ms, `None` for unknown, empty or
+/// irregular (monthly) intervals
+pub fn interval_millis(interval: &str) -> Option<u64> {
+    let split = interval.len().checked_sub(1)?;
+    let (count, unit) = interval.split_at(split);
+    let count: u64 = count.parse().ok().filter(|count| *count > 0)?;
+    let unit = match unit {
+        "s" => 1000,
+        "m" => 60 * 1000,
+        "h" => 60 * 60 * 1000,
+        "d" => DAY_MILLIS,
+        "w" => WEEK_MILLIS,
+        _ => return None,
+    };
+    Some(count * unit)
+}
+
+/// Download every candle opening in `[start_time, end_time]`, `fetch` is called with the
+/// inclusive bounds of each window
+///
+/// Candles are aligned on the interval from the Unix epoch, and from the first Monday for
+/// weekly intervals.
+pub async fn download<T, E, F, Fut>(
+    start_time: u64,
+    end_time: u64,
+    interval_ms: u64,
+    options: DownloadOptions,
+    fetch: F,
+) -> std::result::Result<KlineDownload<T>, E>
+where
+    T: Candle,
+    E: From<ZeroInterval>,
+    F: Fn(u64, u64) -> Fut,
+    Fut: Future<Output = std::result::Result<Vec<T>, E>>,
+{
+    if interval_ms == 0 {
+        return Err(ZeroInterval.into());
+    }
+    // Align on the interval so that windows and expected open times match the exchange
+    let offset = if interval_ms.is_multiple_of(WEEK_MILLIS) {
+        WEEK_OFFSET_MILLIS
+    } else {
+        0
+    };
+    let first_open = offset + start_time.saturating_sub(offset).div_ceil(interval_ms) * interval_ms;
+    let window = interval_ms * options.chunk_size.max(1);
+    let windows: Vec<(u64, u64)> = (first_open..=end_time)
+        .step_by(window as usize)
+        .map(|start| (start, end_time.min(start + window - 1)))
+        .collect();
+
+    let origin = Instant::now();
+    let fetch = &fetch;
+    let chunks: Vec<Vec<T>> = stream::iter(windows.into_iter().enumerate())
+        .map(|(i, (start, end))| async move {
+            tokio::time::sleep_until(origin + options.spacing * i as u32).await;
+            fetch(start, end).await
+        })
+        .buffered(options.concurrency.max(1))
+        .try_collect()
+        .await?;
+
+    let mut klines: Vec<T> = chunks.into_iter().flatten().collect();
+    klines.sort_by_key(Candle::open_time);
+    klines.dedup_by_key(|k| k.open_time());
+
+    let mut missing = vec![];
+    let mut returned = klines.iter().map(Candle::open_time).peekable();
+    for open_time in (first_open..=end_time).step_by(interval_ms as usize) {
+        while returned.next_if(|t| *t < open_time).is_some() {}
+        if returned.next_if_eq(&open_time).is_none() {
+            missing.push(open_time);
+        }
+    }
+
+    Ok(KlineDownload { klines, missing })
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    impl Candle for u64 {
+        fn open_time(&self) -> u64 {
+            *self
+        }
+    }
+
+    #[tokio::test]
+    async fn merges_windows_and_reports_gaps() {
+        let options = DownloadOptions {
+            chunk_size: 3,
+            concurrency: 2,
+            spacing: Duration::from_millis(0),
+        };
+        let download = download(5, 100, 10, options, |start, end| async move {
+            let candles: Vec<u64> = (start..=end).step_by(10).filter(|t| *t != 40).collect();
+            Ok::<_, ZeroInterval>(candles)
+        })
+        .await
+        .unwrap();
+        assert_eq!(download.klines.first(), Some(&10));
+        assert_eq!(download.klines.last(), Some(&100));
+        assert_eq!(download.klines.len(), 9);
+        assert_eq!(download.missing, vec![40]);
+        assert_eq!(interval_millis("4h"), Some(4 * 60 * 60 * 1000));
+        assert_eq!(interval_millis("1M"), None);
+        assert_eq!(interval_millis("0m"), None);
+    }
+
+    #[tokio::test]
+    async fn aligns_weekly_klines_on_monday() {
+        // Monday 2022-01-03 and 2022-01-10 00:00 UTC
+        let mondays = [1641168000000, 1641772800000];
+        let week = interval_millis("1w").unwrap();
+        let options = DownloadOptions::default();
+        let weekly = download(1641081600000, mondays[1], week, options, |start, end| {
+            let candles: Vec<u64> = mondays
+                .into_iter()
+                .filter(|t| (start..=end).contains(t))
+                .collect();
+            async move { Ok::<_, ZeroInterval>(candles) }
+        })
+        .await
+        .unwrap();
+        assert_eq!(weekly.klines, mondays);
+        assert!(weekly.missing.is_empty());
+
+        let zero = download(0, 100, 0, options, |_, _| async { Ok(Vec::<u64>::new()) }).await;
+        assert_eq!(zero.err(), Some(ZeroInterval));
+    }
+}
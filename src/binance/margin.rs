@@ -1,7 +1,9 @@
+use futures::stream::Stream;
 use serde_json::from_str;
 
 use super::client::*;
 use super::errors::*;
+use super::pagination::*;
 use super::rest_model::*;
 use super::util::bool_to_string;
 
@@ -32,15 +34,43 @@ static SAPI_V1_MARGIN_MY_TRADES: &str = "/sapi/v1/margin/myTrades";
 static SAPI_V1_MARGIN_MAX_BORROWABLE: &str = "/sapi/v1/margin/maxBorrowable";
 static SAPI_V1_MARGIN_MAX_TRANSFERABLE: &str = "/sapi/v1/margin/maxTransferable";
 static SAPI_USER_DATA_STREAM: &str = "/sapi/v1/userDataStream";
+static SAPI_USER_DATA_STREAM_ISOLATED: &str = "/sapi/v1/userDataStream/isolated";
 static SAPI_V1_BNB_BURN: &str = "/sapi/v1/bnbBurn";
 static SAPI_V1_MARGIN_INTEREST_RATE_HISTORY: &str = "/sapi/v1/margin/interestRateHistory";
 
+/// Page limits of the margin allOrders and myTrades endpoints, when no limit is sent
+static HISTORY_PAGE_LIMITS: PageLimits = PageLimits {
+    page_size: 500,
+    max_window: 24 * 60 * 60 * 1000,
+    pause: DEFAULT_PAGE_PAUSE,
+};
+
 #[derive(Clone)]
 pub struct Margin {
     pub client: Client,
     pub recv_window: u64,
 }
 
+impl Paged for MarginOrderState {
+    fn page_id(&self) -> u64 {
+        self.order_id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Paged for OwnTradesState {
+    fn page_id(&self) -> u64 {
+        self.id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time as u64
+    }
+}
+
 impl Margin {
     /// Execute transfer between spot account and margin account.
     /// # Examples
@@ -869,6 +899,74 @@ impl Margin {
             .await
     }
 
+    /// Every order of a symbol, paginated transparently from an order id or through a time range
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, margin::*, config::*, pagination::*};
+    /// use futures::StreamExt;
+    /// let margin: Margin = Binance::new_with_env(&Config::testnet());
+    /// let orders = margin.orders_stream("BTCUSDT", None, HistoryCursor::FromId(0));
+    /// let orders: Vec<_> = tokio_test::block_on(orders.collect());
+    /// ```
+    pub fn orders_stream<S>(
+        &self,
+        symbol: S,
+        is_isolated: Option<bool>,
+        cursor: HistoryCursor,
+    ) -> impl Stream<Item = Result<MarginOrderState>>
+    where
+        S: Into<String>,
+    {
+        let margin = self.clone();
+        let symbol = symbol.into();
+        paginate(cursor, HISTORY_PAGE_LIMITS, move |page: PageRequest| {
+            let margin = margin.clone();
+            let query = MarginOrdersQuery {
+                symbol: symbol.clone(),
+                is_isolated: is_isolated.map(bool_to_string),
+                order_id: page.from_id,
+                start_time: page.start_time,
+                end_time: page.end_time,
+                limit: None,
+            };
+            async move { margin.orders(query).await }
+        })
+    }
+
+    /// Every trade of a symbol, paginated transparently from a trade id or through a time range
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, margin::*, config::*, pagination::*};
+    /// use futures::StreamExt;
+    /// let margin: Margin = Binance::new_with_env(&Config::testnet());
+    /// let trades = margin.trades_stream("BTCUSDT", Some(true), HistoryCursor::FromId(0));
+    /// let trades: Vec<_> = tokio_test::block_on(trades.collect());
+    /// ```
+    pub fn trades_stream<S>(
+        &self,
+        symbol: S,
+        is_isolated: Option<bool>,
+        cursor: HistoryCursor,
+    ) -> impl Stream<Item = Result<OwnTradesState>>
+    where
+        S: Into<String>,
+    {
+        let margin = self.clone();
+        let symbol = symbol.into();
+        paginate(cursor, HISTORY_PAGE_LIMITS, move |page: PageRequest| {
+            let margin = margin.clone();
+            let query = MarginOwnTradesQuery {
+                symbol: symbol.clone(),
+                is_isolated: is_isolated.map(bool_to_string),
+                from_id: page.from_id,
+                start_time: page.start_time,
+                end_time: page.end_time,
+                limit: None,
+            };
+            async move { margin.trades(query).await }
+        })
+    }
+
     /// Get an existing oco order state
     /// # Examples
     /// ```rust,no_run
@@ -1048,6 +1146,36 @@ impl Margin {
         Ok(success)
     }
 
+    /// Start the user data stream of an isolated margin pair, the listen key of the pair is
+    /// returned when it already has one
+    pub async fn start_isolated(&self, symbol: &str) -> Result<UserDataStream> {
+        let endpoint = format!("{}?symbol={}", SAPI_USER_DATA_STREAM_ISOLATED, symbol);
+        let data = self.client.post(&endpoint).await?;
+        let user_data_stream: UserDataStream = from_str(data.as_str())?;
+
+        Ok(user_data_stream)
+    }
+
+    /// Keep the user data stream of an isolated margin pair alive
+    pub async fn keep_alive_isolated(&self, symbol: &str, listen_key: &str) -> Result<Success> {
+        let endpoint = format!("{}?symbol={}", SAPI_USER_DATA_STREAM_ISOLATED, symbol);
+        let data = self.client.put(&endpoint, listen_key).await?;
+
+        let success: Success = from_str(data.as_str())?;
+
+        Ok(success)
+    }
+
+    /// Close the user data stream of an isolated margin pair
+    pub async fn close_isolated(&self, symbol: &str, listen_key: &str) -> Result<Success> {
+        let endpoint = format!("{}?symbol={}", SAPI_USER_DATA_STREAM_ISOLATED, symbol);
+        let data = self.client.delete(&endpoint, listen_key).await?;
+
+        let success: Success = from_str(data.as_str())?;
+
+        Ok(success)
+    }
+
     pub async fn isolated_account_limit(&self) -> Result<IsolatedAccountLimit> {
         let q: Option<PairQuery> = None;
 
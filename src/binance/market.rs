@@ -1,7 +1,12 @@
 use super::client::*;
 use super::errors::*;
+use super::klines::*;
+use super::pagination::*;
 use super::rest_model::*;
 use super::util::*;
+use futures::future;
+use futures::stream::{self, Stream, StreamExt, TryStreamExt};
+use serde::de::DeserializeOwned;
 use serde_json::{from_str, Value};
 use std::collections::BTreeMap;
 
@@ -10,7 +15,44 @@ static API_V3_TICKER_PRICE: &str = "/api/v3/ticker/price";
 static API_V3_AVG_PRICE: &str = "/api/v3/avgPrice";
 static API_V3_BOOK_TICKER: &str = "/api/v3/ticker/bookTicker";
 static API_V3_24H_TICKER: &str = "/api/v3/ticker/24hr";
+static API_V3_TICKER: &str = "/api/v3/ticker";
 static API_V3_KLINES: &str = "/api/v3/klines";
+static API_V3_TRADES: &str = "/api/v3/trades";
+static API_V3_HISTORICAL_TRADES: &str = "/api/v3/historicalTrades";
+static API_V3_AGG_TRADES: &str = "/api/v3/aggTrades";
+
+/// Most symbols accepted by a single rolling window ticker request
+pub static ROLLING_WINDOW_MAX_SYMBOLS: usize = 100;
+
+/// Ticker endpoints, to estimate the request weight of a batch before sending it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TickerEndpoint {
+    Price,
+    BookTicker,
+    Stats24h,
+    RollingWindow,
+}
+
+impl TickerEndpoint {
+    /// Request weight for a request on `symbols` symbols, 0 meaning all symbols
+    pub fn weight(&self, symbols: usize) -> u32 {
+        match (self, symbols) {
+            (TickerEndpoint::Price | TickerEndpoint::BookTicker, 1) => 2,
+            (TickerEndpoint::Price | TickerEndpoint::BookTicker, _) => 4,
+            (TickerEndpoint::Stats24h, 1..=20) => 2,
+            (TickerEndpoint::Stats24h, 21..=100) => 40,
+            (TickerEndpoint::Stats24h, _) => 80,
+            (TickerEndpoint::RollingWindow, n) => (4 * n as u32).min(200),
+        }
+    }
+}
+
+/// Page limits of the historicalTrades and aggTrades endpoints
+static TRADES_PAGE_LIMITS: PageLimits = PageLimits {
+    page_size: 1000,
+    max_window: 60 * 60 * 1000,
+    pause: DEFAULT_PAGE_PAUSE,
+};
 
 #[derive(Clone)]
 pub struct Market {
@@ -18,6 +60,32 @@ pub struct Market {
     pub recv_window: u64,
 }
 
+impl Candle for KlineSummary {
+    fn open_time(&self) -> u64 {
+        self.open_time as u64
+    }
+}
+
+impl Paged for Trade {
+    fn page_id(&self) -> u64 {
+        self.id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Paged for AggTrade {
+    fn page_id(&self) -> u64 {
+        self.agg_id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+}
+
 // Market Data endpoints
 impl Market {
     fn symbol_request<S>(&self, symbol: S) -> String
@@ -30,6 +98,16 @@ impl Market {
         build_request(&parameters)
     }
 
+    fn symbols_request<S>(&self, symbols: &[S]) -> Result<BTreeMap<String, String>>
+    where
+        S: AsRef<str>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbols".into(), symbols_param(symbols)?);
+        Ok(parameters)
+    }
+
     /// Order book (Default 100; max 5000)
     /// # Examples
     /// ```rust
@@ -185,6 +263,164 @@ impl Market {
         Ok(stats)
     }
 
+    /// Latest price for several symbols, weighs `TickerEndpoint::Price.weight(symbols.len())`
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let prices = tokio_test::block_on(market.get_symbols_prices(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(prices.is_ok(), "{:?}", prices);
+    /// ```
+    pub async fn get_symbols_prices<S>(&self, symbols: &[S]) -> Result<Vec<SymbolPrice>>
+    where
+        S: AsRef<str>,
+    {
+        let request = build_request(&self.symbols_request(symbols)?);
+        let data = self.client.get(API_V3_TICKER_PRICE, &request).await?;
+        let prices: Vec<SymbolPrice> = from_str(data.as_str())?;
+
+        Ok(prices)
+    }
+
+    /// Best price/qty on the order book for several symbols,
+    /// weighs `TickerEndpoint::BookTicker.weight(symbols.len())`
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let tickers = tokio_test::block_on(market.get_symbols_book_tickers(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(tickers.is_ok(), "{:?}", tickers);
+    /// ```
+    pub async fn get_symbols_book_tickers<S>(&self, symbols: &[S]) -> Result<Vec<Tickers>>
+    where
+        S: AsRef<str>,
+    {
+        let request = build_request(&self.symbols_request(symbols)?);
+        let data = self.client.get(API_V3_BOOK_TICKER, &request).await?;
+        let tickers: Vec<Tickers> = from_str(data.as_str())?;
+
+        Ok(tickers)
+    }
+
+    /// 24hr ticker price change statistics for several symbols,
+    /// weighs `TickerEndpoint::Stats24h.weight(symbols.len())`
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let stats = tokio_test::block_on(market.get_symbols_24h_price_stats(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(stats.is_ok(), "{:?}", stats);
+    /// ```
+    pub async fn get_symbols_24h_price_stats<S>(&self, symbols: &[S]) -> Result<Vec<PriceStats>>
+    where
+        S: AsRef<str>,
+    {
+        self.get_24h_stats(symbols, TickerType::Full).await
+    }
+
+    /// 24hr ticker statistics without the price change for several symbols,
+    /// weighs `TickerEndpoint::Stats24h.weight(symbols.len())`
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let stats = tokio_test::block_on(market.get_symbols_24h_mini_stats(&["BTCUSDT", "ETHUSDT"]));
+    /// assert!(stats.is_ok(), "{:?}", stats);
+    /// ```
+    pub async fn get_symbols_24h_mini_stats<S>(&self, symbols: &[S]) -> Result<Vec<MiniTickerStats>>
+    where
+        S: AsRef<str>,
+    {
+        self.get_24h_stats(symbols, TickerType::Mini).await
+    }
+
+    async fn get_24h_stats<S, T>(&self, symbols: &[S], ticker_type: TickerType) -> Result<Vec<T>>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+    {
+        let query = TickerStatsQuery {
+            symbols: symbols_param(symbols)?,
+            window_size: None,
+            ticker_type,
+        };
+
+        self.client.get_d(API_V3_24H_TICKER, Some(query)).await
+    }
+
+    /// Price change statistics over a rolling window for several symbols
+    ///
+    /// Symbols are requested by batches of `ROLLING_WINDOW_MAX_SYMBOLS`, each batch weighs
+    /// `TickerEndpoint::RollingWindow.weight(batch.len())`
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*, rest_model::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let stats = tokio_test::block_on(market.get_rolling_window_stats(&["BTCUSDT", "ETHUSDT"], WindowSize::Hours(4)));
+    /// assert!(stats.is_ok(), "{:?}", stats);
+    /// ```
+    pub async fn get_rolling_window_stats<S>(
+        &self,
+        symbols: &[S],
+        window_size: WindowSize,
+    ) -> Result<Vec<RollingWindowStats>>
+    where
+        S: AsRef<str>,
+    {
+        self.get_rolling_stats(symbols, window_size, TickerType::Full)
+            .await
+    }
+
+    /// Statistics without the price change over a rolling window for several symbols
+    ///
+    /// Symbols are requested by batches of `ROLLING_WINDOW_MAX_SYMBOLS`, each batch weighs
+    /// `TickerEndpoint::RollingWindow.weight(batch.len())`
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*, rest_model::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let stats = tokio_test::block_on(market.get_rolling_window_mini_stats(&["BTCUSDT", "ETHUSDT"], WindowSize::Days(7)));
+    /// assert!(stats.is_ok(), "{:?}", stats);
+    /// ```
+    pub async fn get_rolling_window_mini_stats<S>(
+        &self,
+        symbols: &[S],
+        window_size: WindowSize,
+    ) -> Result<Vec<MiniTickerStats>>
+    where
+        S: AsRef<str>,
+    {
+        self.get_rolling_stats(symbols, window_size, TickerType::Mini)
+            .await
+    }
+
+    async fn get_rolling_stats<S, T>(
+        &self,
+        symbols: &[S],
+        window_size: WindowSize,
+        ticker_type: TickerType,
+    ) -> Result<Vec<T>>
+    where
+        S: AsRef<str>,
+        T: DeserializeOwned,
+    {
+        if !window_size.is_valid() {
+            return Err(Error::InvalidPeriod(window_size.to_string()));
+        }
+        let mut stats = Vec::with_capacity(symbols.len());
+        for batch in symbols.chunks(ROLLING_WINDOW_MAX_SYMBOLS) {
+            let query = TickerStatsQuery {
+                symbols: symbols_param(batch)?,
+                window_size: Some(window_size.to_string()),
+                ticker_type,
+            };
+            let batch_stats: Vec<T> = self.client.get_d(API_V3_TICKER, Some(query)).await?;
+            stats.extend(batch_stats);
+        }
+
+        Ok(stats)
+    }
+
     /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
     /// https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data
     /// # Examples
@@ -250,4 +486,320 @@ impl Market {
         );
         Ok(klines)
     }
+
+    /// Download every kline opening between start_time and end_time (ms, inclusive),
+    /// splitting the range in as many requests as needed
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, market::*, config::*, klines::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let download = tokio_test::block_on(market.download_klines("BTCUSDT", "1m", 1640995200000, 1643673600000, DownloadOptions::default()));
+    /// assert!(download.is_ok(), "{:?}", download);
+    /// ```
+    pub async fn download_klines<S1, S2>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        start_time: u64,
+        end_time: u64,
+        options: DownloadOptions,
+    ) -> Result<KlineDownload<KlineSummary>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let symbol = symbol.into();
+        let interval = interval.into();
+        let interval_ms =
+            interval_millis(&interval).ok_or_else(|| Error::InvalidPeriod(interval.clone()))?;
+        download(start_time, end_time, interval_ms, options, |start, end| {
+            let klines = self.get_klines(
+                symbol.as_str(),
+                interval.as_str(),
+                options.chunk_size as u16,
+                start,
+                end,
+            );
+            async move {
+                let KlineSummaries::AllKlineSummaries(klines) = klines.await?;
+                Ok(klines)
+            }
+        })
+        .await
+    }
+
+    /// Recent trades of a symbol (Default 500; max 1000)
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let trades = tokio_test::block_on(market.get_trades("BTCUSDT", None));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+
+        let request = build_request(&parameters);
+        let data = self.client.get(API_V3_TRADES, &request).await?;
+        let trades: Vec<Trade> = from_str(data.as_str())?;
+
+        Ok(trades)
+    }
+
+    /// Older trades of a symbol, from a trade id (Default 500; max 1000)
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let trades = tokio_test::block_on(market.get_historical_trades("BTCUSDT", 1_000_000, None));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_historical_trades<S1, S2, S3>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        limit: S3,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(id) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", id));
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+
+        let request = build_request(&parameters);
+        let data = self
+            .client
+            .get_with_api_key(API_V3_HISTORICAL_TRADES, &request)
+            .await?;
+        let trades: Vec<Trade> = from_str(data.as_str())?;
+
+        Ok(trades)
+    }
+
+    /// Aggregated trades of a symbol (Default 500; max 1000)
+    /// start_time and end_time cannot be more than an hour apart
+    /// # Examples
+    /// ```rust
+    /// use binance::{api::*, market::*, config::*};
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let trades = tokio_test::block_on(market.get_agg_trades("BTCUSDT", None, None, None, None));
+    /// assert!(trades.is_ok(), "{:?}", trades);
+    /// ```
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        start_time: S3,
+        end_time: S4,
+        limit: S5,
+    ) -> Result<Vec<AggTrade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+
+        parameters.insert("symbol".into(), symbol.into());
+        if let Some(id) = from_id.into() {
+            parameters.insert("fromId".into(), format!("{}", id));
+        }
+        if let Some(st) = start_time.into() {
+            parameters.insert("startTime".into(), format!("{}", st));
+        }
+        if let Some(et) = end_time.into() {
+            parameters.insert("endTime".into(), format!("{}", et));
+        }
+        if let Some(lt) = limit.into() {
+            parameters.insert("limit".into(), format!("{}", lt));
+        }
+
+        let request = build_request(&parameters);
+        let data = self.client.get(API_V3_AGG_TRADES, &request).await?;
+        let trades: Vec<AggTrade> = from_str(data.as_str())?;
+
+        Ok(trades)
+    }
+
+    /// Every aggregated trade of a symbol, paginated transparently from an aggregate trade id
+    /// or through a time range
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, market::*, config::*, pagination::*};
+    /// use futures::StreamExt;
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let range = HistoryCursor::Between { start_time: 1640995200000, end_time: 1641002400000 };
+    /// let trades: Vec<_> = tokio_test::block_on(market.agg_trades_stream("BTCUSDT", range).collect());
+    /// ```
+    pub fn agg_trades_stream<S>(
+        &self,
+        symbol: S,
+        cursor: HistoryCursor,
+    ) -> impl Stream<Item = Result<AggTrade>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let symbol = symbol.into();
+        paginate(cursor, TRADES_PAGE_LIMITS, move |page: PageRequest| {
+            let market = market.clone();
+            let symbol = symbol.clone();
+            async move {
+                market
+                    .get_agg_trades(
+                        symbol,
+                        page.from_id,
+                        page.start_time,
+                        page.end_time,
+                        TRADES_PAGE_LIMITS.page_size as u16,
+                    )
+                    .await
+            }
+        })
+    }
+
+    /// The complete trade tape of a symbol between start_time and end_time (ms, inclusive)
+    ///
+    /// The id of the first trade is looked up through the aggregated trades, the tape is then
+    /// walked with historical trades from that id.
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance::{api::*, market::*, config::*};
+    /// use futures::StreamExt;
+    /// let market: Market = Binance::new_with_env(&Config::default());
+    /// let trades = market.trades_stream("BTCUSDT", 1640995200000, 1640998800000);
+    /// let trades: Vec<_> = tokio_test::block_on(trades.collect());
+    /// ```
+    pub fn trades_stream<S>(
+        &self,
+        symbol: S,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<Trade>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let symbol = symbol.into();
+        stream::once(async move {
+            let first_id = market
+                .first_trade_id(symbol.as_str(), start_time, end_time)
+                .await?;
+            Ok::<_, Error>(match first_id {
+                Some(id) => market.trades_from(symbol, id).left_stream(),
+                None => stream::empty().right_stream(),
+            })
+        })
+        .try_flatten()
+        .try_take_while(move |trade: &Trade| future::ready(Ok(trade.time <= end_time)))
+    }
+
+    /// Id of the first trade in the time range, looked up one aggTrades window at a time
+    async fn first_trade_id(
+        &self,
+        symbol: &str,
+        start_time: u64,
+        end_time: u64,
+    ) -> Result<Option<u64>> {
+        let mut window_start = start_time;
+        while window_start <= end_time {
+            let window_end =
+                end_time.min(window_start.saturating_add(TRADES_PAGE_LIMITS.max_window - 1));
+            let first = self
+                .get_agg_trades(symbol, None, window_start, window_end, 1)
+                .await?;
+            if let Some(trade) = first.first() {
+                return Ok(Some(trade.first_id));
+            }
+            window_start = window_end + 1;
+            tokio::time::sleep(TRADES_PAGE_LIMITS.pause).await;
+        }
+        Ok(None)
+    }
+
+    fn trades_from(&self, symbol: String, from_id: u64) -> impl Stream<Item = Result<Trade>> {
+        let market = self.clone();
+        paginate(
+            HistoryCursor::FromId(from_id),
+            TRADES_PAGE_LIMITS,
+            move |page: PageRequest| {
+                let market = market.clone();
+                let symbol = symbol.clone();
+                async move {
+                    market
+                        .get_historical_trades(
+                            symbol,
+                            page.from_id,
+                            TRADES_PAGE_LIMITS.page_size as u16,
+                        )
+                        .await
+                }
+            },
+        )
+    }
+}
+
+fn symbols_param<S: AsRef<str>>(symbols: &[S]) -> Result<String> {
+    let symbols: Vec<&str> = symbols.iter().map(AsRef::as_ref).collect();
+
+    Ok(serde_json::to_string(&symbols)?)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn ticker_weights_and_window_sizes() {
+        assert_eq!(TickerEndpoint::Stats24h.weight(20), 2);
+        assert_eq!(TickerEndpoint::Stats24h.weight(21), 40);
+        assert_eq!(TickerEndpoint::Stats24h.weight(0), 80);
+        assert_eq!(TickerEndpoint::RollingWindow.weight(10), 40);
+        assert_eq!(TickerEndpoint::RollingWindow.weight(100), 200);
+        assert_eq!(WindowSize::Hours(4).to_string(), "4h");
+        assert!(!WindowSize::Minutes(60).is_valid());
+        assert!(WindowSize::Days(7).is_valid());
+    }
+
+    #[test]
+    fn ticker_stats_query() {
+        let query = TickerStatsQuery {
+            symbols: symbols_param(&["BTCUSDT", "ETHUSDT"]).unwrap(),
+            window_size: Some(WindowSize::Hours(4).to_string()),
+            ticker_type: TickerType::Mini,
+        };
+        assert_eq!(
+            build_request_p(query).unwrap(),
+            "symbols=%5B%22BTCUSDT%22%2C%22ETHUSDT%22%5D&windowSize=4h&type=MINI"
+        );
+        let query = TickerStatsQuery {
+            symbols: symbols_param(&["BTCUSDT"]).unwrap(),
+            window_size: None,
+            ticker_type: TickerType::Full,
+        };
+        assert_eq!(
+            build_request_p(query).unwrap(),
+            "symbols=%5B%22BTCUSDT%22%5D&type=FULL"
+        );
+    }
 }
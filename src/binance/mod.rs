@@ -19,8 +19,13 @@ pub mod account;
 pub mod api;
 pub mod config;
 pub mod general;
+pub mod isolated_userstream;
+pub mod klines;
 pub mod margin;
 pub mod market;
+pub mod pagination;
 pub mod savings;
+pub mod sub_account;
 pub mod userstream;
 pub mod websockets;
+pub mod ws_api;
@@ -0,0 +1,292 @@
+/*!
+Transparent pagination of history endpoints.
+
+History endpoints (orders, own trades...) return at most one page per call. The helpers in
+this module walk a full history either forward from an id, or through a time range split in
+windows the endpoint accepts, pausing between calls to stay under the rate limit and dropping
+the records that overlap between two consecutive pages.
+
+A time range cannot be walked past a millisecond holding more records than a page, the stream
+then ends with a `PageOverflow` error instead of skipping the records it could not fetch.
+*/
+
+use std::collections::VecDeque;
+use std::future::Future;
+use std::time::Duration;
+
+use futures::stream::{self, Stream};
+use thiserror::Error;
+
+use super::util::HashSet;
+
+/// Default pause between two page requests
+pub static DEFAULT_PAGE_PAUSE: Duration = Duration::from_millis(250);
+
+/// Where to start walking a history from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HistoryCursor {
+    /// Every record from this id (inclusive) onwards
+    FromId(u64),
+    /// Every record in the time range (inclusive, in ms)
+    Between { start_time: u64, end_time: u64 },
+}
+
+/// The parameters of a single page request
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PageRequest {
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+}
+
+/// A full page of a time range was sent within a single millisecond, the records of this
+/// millisecond past the page cannot be fetched by time
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Error)]
+#[error("more than a page of records at {time} ms, walk the history by id instead")]
+pub struct PageOverflow {
+    pub time: u64,
+}
+
+/// A record that can be paged through
+pub trait Paged {
+    fn page_id(&self) -> u64;
+    fn page_time(&self) -> u64;
+}
+
+/// How the endpoint limits a single request
+#[derive(Debug, Clone, Copy)]
+pub struct PageLimits {
+    /// Number of records in a full page
+    pub page_size: usize,
+    /// Largest time range accepted in a single request, in ms
+    pub max_window: u64,
+    /// Pause between two requests
+    pub pause: Duration,
+}
+
+struct PagerState<T, E, F> {
+    fetch: F,
+    limits: PageLimits,
+    end_time: Option<u64>,
+    next: Option<PageRequest>,
+    buffer: VecDeque<T>,
+    /// Sent once the records of the last page are
+    error: Option<E>,
+    last_page_ids: HashSet<u64>,
+    started: bool,
+}
+
+impl PageLimits {
+    fn window(&self, start_time: u64, end_time: u64) -> PageRequest {
+        PageRequest {
+            from_id: None,
+            start_time: Some(start_time),
+            end_time: Some(end_time.min(start_time.saturating_add(self.max_window - 1))),
+        }
+    }
+}
+
+/// Stream every record of a history, `fetch` is called once per page
+pub fn paginate<T, E, F, Fut>(
+    cursor: HistoryCursor,
+    limits: PageLimits,
+    fetch: F,
+) -> impl Stream<Item = std::result::Result<T, E>>
+where
+    T: Paged,
+    E: From<PageOverflow>,
+    F: FnMut(PageRequest) -> Fut,
+    Fut: Future<Output = std::result::Result<Vec<T>, E>>,
+{
+    let (first, end_time) = match cursor {
+        HistoryCursor::FromId(id) => (
+            PageRequest {
+                from_id: Some(id),
+                ..PageRequest::default()
+            },
+            None,
+        ),
+        HistoryCursor::Between {
+            start_time,
+            end_time,
+        } => (limits.window(start_time, end_time), Some(end_time)),
+    };
+    let state = PagerState {
+        fetch,
+        limits,
+        end_time,
+        next: (end_time.is_none() || first.start_time <= end_time).then_some(first),
+        buffer: VecDeque::new(),
+        error: None,
+        last_page_ids: HashSet::default(),
+        started: false,
+    };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(record) = state.buffer.pop_front() {
+                return Some((Ok(record), state));
+            }
+            if let Some(e) = state.error.take() {
+                return Some((Err(e), state));
+            }
+            let request = state.next.take()?;
+            if state.started {
+                tokio::time::sleep(state.limits.pause).await;
+            }
+            state.started = true;
+            let mut page = match (state.fetch)(request).await {
+                Ok(page) => page,
+                Err(e) => return Some((Err(e), state)),
+            };
+            page.sort_by_key(|r| (r.page_time(), r.page_id()));
+            state.next = match next_request(&request, &page, &state.limits, state.end_time) {
+                Ok(next) => next,
+                Err(e) => {
+                    state.error = Some(e.into());
+                    None
+                }
+            };
+            let page_ids: HashSet<u64> = page.iter().map(Paged::page_id).collect();
+            state.buffer.extend(
+                page.into_iter()
+                    .filter(|r| !state.last_page_ids.contains(&r.page_id())),
+            );
+            state.last_page_ids = page_ids;
+        }
+    })
+}
+
+fn next_request<T: Paged>(
+    request: &PageRequest,
+    page: &[T],
+    limits: &PageLimits,
+    end_time: Option<u64>,
+) -> std::result::Result<Option<PageRequest>, PageOverflow> {
+    let full = page.len() >= limits.page_size;
+    let next = match (request.from_id, end_time) {
+        (Some(_), _) => {
+            if !full {
+                return Ok(None);
+            }
+            page.iter()
+                .map(Paged::page_id)
+                .max()
+                .map(|last_id| PageRequest {
+                    from_id: Some(last_id + 1),
+                    ..PageRequest::default()
+                })
+        }
+        (None, Some(end_time)) => {
+            let (Some(start_time), Some(window_end)) = (request.start_time, request.end_time)
+            else {
+                return Ok(None);
+            };
+            let next_start = if full {
+                // The page may stop in the middle of a millisecond, restart from it and let
+                // the overlapping records be dropped, unless the whole page is in it
+                let last_time = page
+                    .iter()
+                    .map(Paged::page_time)
+                    .max()
+                    .unwrap_or(start_time);
+                if last_time <= start_time {
+                    return Err(PageOverflow { time: start_time });
+                }
+                last_time
+            } else {
+                window_end + 1
+            };
+            (next_start <= end_time).then(|| limits.window(next_start, end_time))
+        }
+        (None, None) => None,
+    };
+    Ok(next)
+}
+
+#[cfg(test)]
+mod test {
+    use std::time::Duration;
+
+    use futures::StreamExt;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct Record(u64, u64);
+
+    impl Paged for Record {
+        fn page_id(&self) -> u64 {
+            self.0
+        }
+
+        fn page_time(&self) -> u64 {
+            self.1
+        }
+    }
+
+    static LIMITS: PageLimits = PageLimits {
+        page_size: 3,
+        max_window: 10,
+        pause: Duration::from_millis(0),
+    };
+
+    fn history() -> Vec<Record> {
+        (0..10).map(|id| Record(id, id / 2)).collect()
+    }
+
+    fn fetch(request: PageRequest) -> std::future::Ready<Result<Vec<Record>, PageOverflow>> {
+        fetch_from(history(), request)
+    }
+
+    fn fetch_from(
+        history: Vec<Record>,
+        request: PageRequest,
+    ) -> std::future::Ready<Result<Vec<Record>, PageOverflow>> {
+        let page = history
+            .into_iter()
+            .filter(|r| request.from_id.is_none_or(|id| r.0 >= id))
+            .filter(|r| request.start_time.is_none_or(|t| r.1 >= t))
+            .filter(|r| request.end_time.is_none_or(|t| r.1 <= t))
+            .take(LIMITS.page_size)
+            .collect();
+        std::future::ready(Ok(page))
+    }
+
+    #[tokio::test]
+    async fn walks_ids_and_time_windows_without_duplicates() {
+        let by_id: Vec<_> = paginate(HistoryCursor::FromId(2), LIMITS, fetch)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(by_id, history()[2..].to_vec());
+
+        let range = HistoryCursor::Between {
+            start_time: 0,
+            end_time: 3,
+        };
+        let by_time: Vec<_> = paginate(range, LIMITS, fetch)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+        assert_eq!(by_time, history()[..8].to_vec());
+    }
+
+    #[tokio::test]
+    async fn fails_on_more_than_a_page_in_a_millisecond() {
+        let history: Vec<_> = (0..=LIMITS.page_size as u64)
+            .map(|id| Record(id, 5))
+            .collect();
+        let range = HistoryCursor::Between {
+            start_time: 0,
+            end_time: 9,
+        };
+        let walked: Vec<_> = paginate(range, LIMITS, |request| {
+            fetch_from(history.clone(), request)
+        })
+        .collect()
+        .await;
+        let records: Vec<_> = walked.iter().filter_map(|r| r.ok()).collect();
+        assert_eq!(records, history[..LIMITS.page_size].to_vec());
+        assert_eq!(walked.last(), Some(&Err(PageOverflow { time: 5 })));
+    }
+}
@@ -207,6 +207,88 @@ pub struct OrderCanceled {
     pub client_order_id: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceMode {
+    /// Do not place the new order if the cancellation fails
+    StopOnFailure,
+    /// Place the new order whether the cancellation succeeds or not
+    AllowFailure,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelRestrictions {
+    /// Only cancel the order if it is still new
+    OnlyNew,
+    /// Only cancel the order if it is partially filled
+    OnlyPartiallyFilled,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum CancelReplaceResult {
+    Success,
+    Failure,
+    NotAttempted,
+}
+
+/// The error a single leg of a cancel-replace failed with
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LegError {
+    pub code: i16,
+    pub msg: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(untagged)]
+pub enum CancelReplaceLeg<T> {
+    Done(T),
+    Failed(LegError),
+}
+
+/// Outcome of a cancel-replace, the responses are absent for legs that were not attempted
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceResponse {
+    pub cancel_result: CancelReplaceResult,
+    pub new_order_result: CancelReplaceResult,
+    pub cancel_response: Option<CancelReplaceLeg<OrderCanceled>>,
+    pub new_order_response: Option<CancelReplaceLeg<Transaction>>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AmendedOrder {
+    pub symbol: String,
+    pub order_id: u64,
+    pub order_list_id: i64,
+    pub orig_client_order_id: String,
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cumulative_quote_qty: f64,
+    pub status: OrderStatus,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub side: OrderSide,
+}
+
+/// Response of an order amended in place, the order keeps its priority in the book
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderAmendment {
+    pub transact_time: u64,
+    pub execution_id: u64,
+    pub amended_order: AmendedOrder,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Fill {
@@ -239,6 +321,8 @@ pub struct Transaction {
     #[serde(rename = "type")]
     pub order_type: OrderType,
     pub side: OrderSide,
+    /// Only sent with full responses
+    #[serde(default)]
     pub fills: Vec<Fill>,
 }
 
@@ -360,9 +444,47 @@ pub struct TradeHistory {
     pub is_best_match: bool,
 }
 
+/// A public trade of a symbol
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    pub time: u64,
+    pub is_buyer_maker: bool,
+    pub is_best_match: bool,
+}
+
+/// Trades filled at the same time, from the same order, at the same price
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AggTrade {
+    #[serde(rename = "a")]
+    pub agg_id: u64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: f64,
+    #[serde(rename = "f")]
+    pub first_id: u64,
+    #[serde(rename = "l")]
+    pub last_id: u64,
+    #[serde(rename = "T")]
+    pub time: u64,
+    #[serde(rename = "m")]
+    pub maker: bool,
+    #[serde(rename = "M")]
+    pub best_match: bool,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PriceStats {
+    pub symbol: String,
     pub price_change: String,
     pub price_change_percent: String,
     pub weighted_avg_price: String,
@@ -389,6 +511,108 @@ pub struct PriceStats {
     pub count: u64,
 }
 
+/// Response type of the ticker statistics endpoints
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TickerType {
+    Full,
+    Mini,
+}
+
+/// Window of the rolling ticker statistics, from 1m to 59m, 1h to 23h or 1d to 7d
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WindowSize {
+    Minutes(u8),
+    Hours(u8),
+    Days(u8),
+}
+
+impl WindowSize {
+    pub fn is_valid(&self) -> bool {
+        match *self {
+            WindowSize::Minutes(m) => (1..=59).contains(&m),
+            WindowSize::Hours(h) => (1..=23).contains(&h),
+            WindowSize::Days(d) => (1..=7).contains(&d),
+        }
+    }
+}
+
+impl std::fmt::Display for WindowSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            WindowSize::Minutes(m) => write!(f, "{}m", m),
+            WindowSize::Hours(h) => write!(f, "{}h", h),
+            WindowSize::Days(d) => write!(f, "{}d", d),
+        }
+    }
+}
+
+/// Query of the 24hr and rolling window ticker statistics endpoints
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerStatsQuery {
+    /// JSON array of the symbols, such as `["BTCUSDT","ETHUSDT"]`
+    pub symbols: String,
+    /// Rolling window endpoint only, see `WindowSize`
+    pub window_size: Option<String>,
+    #[serde(rename = "type")]
+    pub ticker_type: TickerType,
+}
+
+/// Price change statistics over a rolling window
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RollingWindowStats {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: f64,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: f64,
+    #[serde(with = "string_or_float")]
+    pub weighted_avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
+/// Ticker statistics without the price change, returned with `TickerType::Mini`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MiniTickerStats {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: i64,
+    pub last_id: i64,
+    pub count: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub enum MarginTransferType {
     FromMainToMargin = 1,
@@ -617,6 +841,23 @@ pub struct MarginOCOOrderResult {
     pub margin_buy_borrow_asset: Option<String>,
     pub is_isolated: Option<bool>,
     pub orders: Vec<OCOOrderDetail>,
+    #[serde(default)]
+    pub order_reports: Vec<OCOOrderReport>,
+}
+
+/// A spot order list (OCO), order reports are only returned when placing or canceling the list
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderList {
+    pub order_list_id: u64,
+    pub contingency_type: ContingencyType,
+    pub list_status_type: OCOStatus,
+    pub list_order_status: OCOOrderStatus,
+    pub list_client_order_id: Option<String>,
+    pub transaction_time: u128,
+    pub symbol: String,
+    pub orders: Vec<OCOOrderDetail>,
+    #[serde(default)]
     pub order_reports: Vec<OCOOrderReport>,
 }
 
@@ -647,9 +888,9 @@ pub struct OCOOrderReport {
     pub time_in_force: TimeInForce,
     #[serde(rename = "type")]
     pub order_type: OrderType,
-    #[serde(rename = "type")]
     pub side: OrderSide,
-    #[serde(with = "string_or_float")]
+    /// Zero for the limit leg
+    #[serde(default, with = "string_or_float")]
     pub stop_price: f64,
 }
 
@@ -688,7 +929,8 @@ pub struct MarginOrdersQuery {
     pub symbol: String,
     /// "TRUE" or "FALSE", default is "FALSE"
     pub is_isolated: Option<String>,
-    pub order_id: u64,
+    /// Unset to query a time range, the endpoint ignores the time range otherwise
+    pub order_id: Option<u64>,
     pub start_time: Option<u64>,
     pub end_time: Option<u64>,
     pub limit: Option<u8>,
@@ -700,7 +942,8 @@ pub struct MarginOwnTradesQuery {
     pub symbol: String,
     /// "TRUE" or "FALSE", default is "FALSE"
     pub is_isolated: Option<String>,
-    pub from_id: u64,
+    /// Unset to query a time range, the endpoint ignores the time range otherwise
+    pub from_id: Option<u64>,
     pub start_time: Option<u64>,
     pub end_time: Option<u64>,
     pub limit: Option<u8>,
@@ -1195,6 +1438,7 @@ pub enum OrderStatus {
 pub enum OCOStatus {
     Response,
     ExecStarted,
+    Updated,
     AllDone,
 }
 
@@ -1402,11 +1646,77 @@ pub(crate) mod string_or_float_opt {
     }
 }
 
+/// Integers, such as timestamps, some endpoints send as strings
+pub(crate) mod string_or_u64 {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<u64, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrU64 {
+            String(String),
+            U64(u64),
+        }
+
+        match StringOrU64::deserialize(deserializer)? {
+            StringOrU64::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrU64::U64(i) => Ok(i),
+        }
+    }
+}
+
+/// Booleans some endpoints send as strings
+pub(crate) mod string_or_bool {
+    use std::fmt;
+
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: fmt::Display,
+        S: Serializer,
+    {
+        serializer.collect_str(value)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum StringOrBool {
+            String(String),
+            Bool(bool),
+        }
+
+        match StringOrBool::deserialize(deserializer)? {
+            StringOrBool::String(s) => s.parse().map_err(de::Error::custom),
+            StringOrBool::Bool(i) => Ok(i),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::path::PathBuf;
 
-    use super::ExchangeInformation;
+    use super::{
+        CancelReplaceLeg, CancelReplaceResponse, CancelReplaceResult, ExchangeInformation,
+    };
 
     #[test]
     fn exchange_info_serde() {
@@ -1416,4 +1726,41 @@ mod test {
         let result = serde_json::from_str::<ExchangeInformation>(&fc);
         assert!(result.is_ok(), "{:?}", result);
     }
+    #[test]
+    fn cancel_replace_partial_failure_serde() {
+        let data = r#"{
+            "cancelResult": "SUCCESS",
+            "newOrderResult": "FAILURE",
+            "cancelResponse": {
+                "symbol": "BTCUSDT",
+                "origClientOrderId": "DnLo3vTAQcjha43lAZhZ0y",
+                "orderId": 9,
+                "orderListId": -1,
+                "clientOrderId": "osxN3JXAtJvKvCqGeMWMVR",
+                "price": "0.01000000",
+                "origQty": "0.000100",
+                "executedQty": "0.00000000",
+                "cummulativeQuoteQty": "0.00000000",
+                "status": "CANCELED",
+                "timeInForce": "GTC",
+                "type": "LIMIT",
+                "side": "SELL"
+            },
+            "newOrderResponse": {
+                "code": -2010,
+                "msg": "Order would immediately match and take."
+            }
+        }"#;
+        let result: CancelReplaceResponse = serde_json::from_str(data).unwrap();
+        assert_eq!(result.cancel_result, CancelReplaceResult::Success);
+        assert_eq!(result.new_order_result, CancelReplaceResult::Failure);
+        assert!(matches!(
+            result.cancel_response,
+            Some(CancelReplaceLeg::Done(_))
+        ));
+        assert!(matches!(
+            result.new_order_response,
+            Some(CancelReplaceLeg::Failed(e)) if e.code == -2010
+        ));
+    }
 }
@@ -2,8 +2,26 @@ use super::client::*;
 use super::errors::*;
 use super::rest_model::*;
 use super::util::*;
+use rust_decimal::Decimal;
 use std::collections::BTreeMap;
 
+static SAPI_V1_CAPITAL_WITHDRAW_APPLY: &str = "/sapi/v1/capital/withdraw/apply";
+static SAPI_V1_CAPITAL_WITHDRAW_HISTORY: &str = "/sapi/v1/capital/withdraw/history";
+static SAPI_V1_CAPITAL_DEPOSIT_HISTORY: &str = "/sapi/v1/capital/deposit/hisrec";
+static SAPI_V1_ASSET_TRANSFER: &str = "/sapi/v1/asset/transfer";
+static SAPI_V1_EARN_FLEXIBLE_LIST: &str = "/sapi/v1/simple-earn/flexible/list";
+static SAPI_V1_EARN_LOCKED_LIST: &str = "/sapi/v1/simple-earn/locked/list";
+static SAPI_V1_EARN_FLEXIBLE_SUBSCRIBE: &str = "/sapi/v1/simple-earn/flexible/subscribe";
+static SAPI_V1_EARN_LOCKED_SUBSCRIBE: &str = "/sapi/v1/simple-earn/locked/subscribe";
+static SAPI_V1_EARN_FLEXIBLE_REDEEM: &str = "/sapi/v1/simple-earn/flexible/redeem";
+static SAPI_V1_EARN_LOCKED_REDEEM: &str = "/sapi/v1/simple-earn/locked/redeem";
+static SAPI_V1_EARN_FLEXIBLE_POSITION: &str = "/sapi/v1/simple-earn/flexible/position";
+static SAPI_V1_EARN_LOCKED_POSITION: &str = "/sapi/v1/simple-earn/locked/position";
+static SAPI_V1_EARN_FLEXIBLE_REWARDS: &str = "/sapi/v1/simple-earn/flexible/history/rewardsRecord";
+static SAPI_V1_EARN_LOCKED_REWARDS: &str = "/sapi/v1/simple-earn/locked/history/rewardsRecord";
+static SAPI_V1_EARN_FLEXIBLE_QUOTA: &str = "/sapi/v1/simple-earn/flexible/personalLeftQuota";
+static SAPI_V1_EARN_LOCKED_QUOTA: &str = "/sapi/v1/simple-earn/locked/personalLeftQuota";
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct CoinInfo {
@@ -55,6 +73,8 @@ pub struct Network {
     pub withdraw_fee: f64,
     #[serde(with = "string_or_float")]
     pub withdraw_min: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub withdraw_max: Option<f64>,
     // pub insert_time: Option<u64>, //commented out for now, because they are not inside the actual response (only the api doc example)
     // pub update_time: Option<u64>,
     pub withdraw_integer_multiple: Option<String>,
@@ -83,6 +103,801 @@ pub struct DepositAddress {
     pub url: String,
 }
 
+/// Withdrawal of a coin to an address, the amount includes the network fee
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRequest {
+    pub coin: String,
+    /// Client id of the withdrawal
+    pub withdraw_order_id: Option<String>,
+    /// The default network of the coin when not set
+    pub network: Option<String>,
+    pub address: String,
+    /// Secondary address identifier, such as a memo
+    pub address_tag: Option<String>,
+    pub amount: f64,
+    /// For internal transfers, true to have the fee paid by the destination account
+    pub transaction_fee_flag: Option<bool>,
+    /// Description of the address, saved in the address book
+    pub name: Option<String>,
+    /// Wallet the amount is withdrawn from, 0 for spot (default) and 1 for funding
+    pub wallet_type: Option<u8>,
+}
+
+impl WithdrawRequest {
+    /// Check the withdrawal against the limits of the network it is sent on
+    pub fn validate(&self, network: &Network) -> Result<()> {
+        let invalid = |msg: String| Err(Error::InvalidWithdrawal(msg));
+        if network.coin != self.coin {
+            return invalid(format!(
+                "network of {} used for {}",
+                network.coin, self.coin
+            ));
+        }
+        if let Some(name) = &self.network {
+            if *name != network.network {
+                return invalid(format!("network {} used for {}", network.network, name));
+            }
+        }
+        if !network.withdraw_enable {
+            return invalid(format!(
+                "withdrawals of {} on {} are disabled: {}",
+                self.coin,
+                network.network,
+                network.withdraw_desc.as_deref().unwrap_or_default()
+            ));
+        }
+        if self.amount < network.withdraw_min {
+            return invalid(format!(
+                "amount {} is below the minimum of {}",
+                self.amount, network.withdraw_min
+            ));
+        }
+        if let Some(max) = network.withdraw_max.filter(|max| *max > 0.0) {
+            if self.amount > max {
+                return invalid(format!(
+                    "amount {} is above the maximum of {}",
+                    self.amount, max
+                ));
+            }
+        }
+        if self.amount <= network.withdraw_fee {
+            return invalid(format!(
+                "amount {} does not cover the fee of {}",
+                self.amount, network.withdraw_fee
+            ));
+        }
+        // Compared as decimals, amount / multiple loses the last digits of large amounts
+        let multiple = network
+            .withdraw_integer_multiple
+            .as_deref()
+            .and_then(|m| m.parse::<Decimal>().ok())
+            .filter(|m| *m > Decimal::ZERO);
+        if let Some(multiple) = multiple {
+            let amount = self.amount.to_string().parse::<Decimal>();
+            if !amount.is_ok_and(|amount| (amount % multiple).is_zero()) {
+                return invalid(format!(
+                    "amount {} is not a multiple of {}",
+                    self.amount, multiple
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawResponse {
+    pub id: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum DepositStatus {
+    Pending,
+    Success,
+    Rejected,
+    /// Credited to the account, but cannot be withdrawn yet
+    CreditedCannotWithdraw,
+    WrongDeposit,
+    WaitingUserConfirm,
+    Other(u8),
+}
+
+impl From<u8> for DepositStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => DepositStatus::Pending,
+            1 => DepositStatus::Success,
+            2 => DepositStatus::Rejected,
+            6 => DepositStatus::CreditedCannotWithdraw,
+            7 => DepositStatus::WrongDeposit,
+            8 => DepositStatus::WaitingUserConfirm,
+            other => DepositStatus::Other(other),
+        }
+    }
+}
+
+impl From<DepositStatus> for u8 {
+    fn from(status: DepositStatus) -> Self {
+        match status {
+            DepositStatus::Pending => 0,
+            DepositStatus::Success => 1,
+            DepositStatus::Rejected => 2,
+            DepositStatus::CreditedCannotWithdraw => 6,
+            DepositStatus::WrongDeposit => 7,
+            DepositStatus::WaitingUserConfirm => 8,
+            DepositStatus::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum WithdrawStatus {
+    EmailSent,
+    Cancelled,
+    AwaitingApproval,
+    Rejected,
+    Processing,
+    Failure,
+    Completed,
+    Other(u8),
+}
+
+impl From<u8> for WithdrawStatus {
+    fn from(status: u8) -> Self {
+        match status {
+            0 => WithdrawStatus::EmailSent,
+            1 => WithdrawStatus::Cancelled,
+            2 => WithdrawStatus::AwaitingApproval,
+            3 => WithdrawStatus::Rejected,
+            4 => WithdrawStatus::Processing,
+            5 => WithdrawStatus::Failure,
+            6 => WithdrawStatus::Completed,
+            other => WithdrawStatus::Other(other),
+        }
+    }
+}
+
+impl From<WithdrawStatus> for u8 {
+    fn from(status: WithdrawStatus) -> Self {
+        match status {
+            WithdrawStatus::EmailSent => 0,
+            WithdrawStatus::Cancelled => 1,
+            WithdrawStatus::AwaitingApproval => 2,
+            WithdrawStatus::Rejected => 3,
+            WithdrawStatus::Processing => 4,
+            WithdrawStatus::Failure => 5,
+            WithdrawStatus::Completed => 6,
+            WithdrawStatus::Other(other) => other,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub coin: String,
+    pub network: String,
+    pub status: DepositStatus,
+    pub address: String,
+    pub address_tag: String,
+    pub tx_id: String,
+    pub insert_time: u64,
+    /// 1 for internal transfers, 0 for external ones
+    pub transfer_type: u8,
+    /// Confirmations, such as "12/12"
+    pub confirm_times: String,
+    pub unlock_confirm: u32,
+    /// 0 for spot, 1 for funding
+    pub wallet_type: u8,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawRecord {
+    pub id: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub transaction_fee: f64,
+    pub coin: String,
+    pub status: WithdrawStatus,
+    pub address: String,
+    /// Not sent for the withdrawals without a transaction yet
+    #[serde(default)]
+    pub tx_id: Option<String>,
+    /// UTC time, such as "2019-10-12 11:12:02"
+    pub apply_time: String,
+    pub network: String,
+    /// 1 for internal transfers, 0 for external ones
+    pub transfer_type: u8,
+    /// Not sent when the withdrawal has no client id
+    #[serde(default)]
+    pub withdraw_order_id: Option<String>,
+    /// Reason of a failure
+    #[serde(default)]
+    pub info: Option<String>,
+    /// Confirmations of the withdrawal
+    #[serde(default)]
+    pub confirm_no: Option<u32>,
+    /// 0 for spot, 1 for funding
+    pub wallet_type: u8,
+    #[serde(default)]
+    pub tx_key: Option<String>,
+    /// UTC time, such as "2019-10-12 11:13:02"
+    #[serde(default)]
+    pub complete_time: Option<String>,
+}
+
+/// A wallet of the account, between which universal transfers move assets
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Wallet {
+    /// Spot
+    Main,
+    /// USD-M futures
+    UmFuture,
+    /// COIN-M futures
+    CmFuture,
+    /// Cross margin
+    Margin,
+    IsolatedMargin,
+    Funding,
+    /// European options
+    Option,
+    PortfolioMargin,
+}
+
+/// Route of a universal transfer, from one wallet to another
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UniversalTransferType {
+    #[serde(rename = "MAIN_UMFUTURE")]
+    MainToUmFuture,
+    #[serde(rename = "MAIN_CMFUTURE")]
+    MainToCmFuture,
+    #[serde(rename = "MAIN_MARGIN")]
+    MainToMargin,
+    #[serde(rename = "UMFUTURE_MAIN")]
+    UmFutureToMain,
+    #[serde(rename = "UMFUTURE_MARGIN")]
+    UmFutureToMargin,
+    #[serde(rename = "CMFUTURE_MAIN")]
+    CmFutureToMain,
+    #[serde(rename = "CMFUTURE_MARGIN")]
+    CmFutureToMargin,
+    #[serde(rename = "MARGIN_MAIN")]
+    MarginToMain,
+    #[serde(rename = "MARGIN_UMFUTURE")]
+    MarginToUmFuture,
+    #[serde(rename = "MARGIN_CMFUTURE")]
+    MarginToCmFuture,
+    #[serde(rename = "ISOLATEDMARGIN_MARGIN")]
+    IsolatedMarginToMargin,
+    #[serde(rename = "MARGIN_ISOLATEDMARGIN")]
+    MarginToIsolatedMargin,
+    #[serde(rename = "ISOLATEDMARGIN_ISOLATEDMARGIN")]
+    IsolatedMarginToIsolatedMargin,
+    #[serde(rename = "MAIN_FUNDING")]
+    MainToFunding,
+    #[serde(rename = "FUNDING_MAIN")]
+    FundingToMain,
+    #[serde(rename = "FUNDING_UMFUTURE")]
+    FundingToUmFuture,
+    #[serde(rename = "UMFUTURE_FUNDING")]
+    UmFutureToFunding,
+    #[serde(rename = "MARGIN_FUNDING")]
+    MarginToFunding,
+    #[serde(rename = "FUNDING_MARGIN")]
+    FundingToMargin,
+    #[serde(rename = "FUNDING_CMFUTURE")]
+    FundingToCmFuture,
+    #[serde(rename = "CMFUTURE_FUNDING")]
+    CmFutureToFunding,
+    #[serde(rename = "MAIN_OPTION")]
+    MainToOption,
+    #[serde(rename = "OPTION_MAIN")]
+    OptionToMain,
+    #[serde(rename = "UMFUTURE_OPTION")]
+    UmFutureToOption,
+    #[serde(rename = "OPTION_UMFUTURE")]
+    OptionToUmFuture,
+    #[serde(rename = "MARGIN_OPTION")]
+    MarginToOption,
+    #[serde(rename = "OPTION_MARGIN")]
+    OptionToMargin,
+    #[serde(rename = "FUNDING_OPTION")]
+    FundingToOption,
+    #[serde(rename = "OPTION_FUNDING")]
+    OptionToFunding,
+    #[serde(rename = "MAIN_PORTFOLIO_MARGIN")]
+    MainToPortfolioMargin,
+    #[serde(rename = "PORTFOLIO_MARGIN_MAIN")]
+    PortfolioMarginToMain,
+}
+
+impl UniversalTransferType {
+    /// Every documented route
+    pub const ALL: [UniversalTransferType; 31] = [
+        UniversalTransferType::MainToUmFuture,
+        UniversalTransferType::MainToCmFuture,
+        UniversalTransferType::MainToMargin,
+        UniversalTransferType::UmFutureToMain,
+        UniversalTransferType::UmFutureToMargin,
+        UniversalTransferType::CmFutureToMain,
+        UniversalTransferType::CmFutureToMargin,
+        UniversalTransferType::MarginToMain,
+        UniversalTransferType::MarginToUmFuture,
+        UniversalTransferType::MarginToCmFuture,
+        UniversalTransferType::IsolatedMarginToMargin,
+        UniversalTransferType::MarginToIsolatedMargin,
+        UniversalTransferType::IsolatedMarginToIsolatedMargin,
+        UniversalTransferType::MainToFunding,
+        UniversalTransferType::FundingToMain,
+        UniversalTransferType::FundingToUmFuture,
+        UniversalTransferType::UmFutureToFunding,
+        UniversalTransferType::MarginToFunding,
+        UniversalTransferType::FundingToMargin,
+        UniversalTransferType::FundingToCmFuture,
+        UniversalTransferType::CmFutureToFunding,
+        UniversalTransferType::MainToOption,
+        UniversalTransferType::OptionToMain,
+        UniversalTransferType::UmFutureToOption,
+        UniversalTransferType::OptionToUmFuture,
+        UniversalTransferType::MarginToOption,
+        UniversalTransferType::OptionToMargin,
+        UniversalTransferType::FundingToOption,
+        UniversalTransferType::OptionToFunding,
+        UniversalTransferType::MainToPortfolioMargin,
+        UniversalTransferType::PortfolioMarginToMain,
+    ];
+
+    /// The wallets the route moves assets from and to
+    pub fn wallets(self) -> (Wallet, Wallet) {
+        match self {
+            UniversalTransferType::MainToUmFuture => (Wallet::Main, Wallet::UmFuture),
+            UniversalTransferType::MainToCmFuture => (Wallet::Main, Wallet::CmFuture),
+            UniversalTransferType::MainToMargin => (Wallet::Main, Wallet::Margin),
+            UniversalTransferType::UmFutureToMain => (Wallet::UmFuture, Wallet::Main),
+            UniversalTransferType::UmFutureToMargin => (Wallet::UmFuture, Wallet::Margin),
+            UniversalTransferType::CmFutureToMain => (Wallet::CmFuture, Wallet::Main),
+            UniversalTransferType::CmFutureToMargin => (Wallet::CmFuture, Wallet::Margin),
+            UniversalTransferType::MarginToMain => (Wallet::Margin, Wallet::Main),
+            UniversalTransferType::MarginToUmFuture => (Wallet::Margin, Wallet::UmFuture),
+            UniversalTransferType::MarginToCmFuture => (Wallet::Margin, Wallet::CmFuture),
+            UniversalTransferType::IsolatedMarginToMargin => {
+                (Wallet::IsolatedMargin, Wallet::Margin)
+            }
+            UniversalTransferType::MarginToIsolatedMargin => {
+                (Wallet::Margin, Wallet::IsolatedMargin)
+            }
+            UniversalTransferType::IsolatedMarginToIsolatedMargin => {
+                (Wallet::IsolatedMargin, Wallet::IsolatedMargin)
+            }
+            UniversalTransferType::MainToFunding => (Wallet::Main, Wallet::Funding),
+            UniversalTransferType::FundingToMain => (Wallet::Funding, Wallet::Main),
+            UniversalTransferType::FundingToUmFuture => (Wallet::Funding, Wallet::UmFuture),
+            UniversalTransferType::UmFutureToFunding => (Wallet::UmFuture, Wallet::Funding),
+            UniversalTransferType::MarginToFunding => (Wallet::Margin, Wallet::Funding),
+            UniversalTransferType::FundingToMargin => (Wallet::Funding, Wallet::Margin),
+            UniversalTransferType::FundingToCmFuture => (Wallet::Funding, Wallet::CmFuture),
+            UniversalTransferType::CmFutureToFunding => (Wallet::CmFuture, Wallet::Funding),
+            UniversalTransferType::MainToOption => (Wallet::Main, Wallet::Option),
+            UniversalTransferType::OptionToMain => (Wallet::Option, Wallet::Main),
+            UniversalTransferType::UmFutureToOption => (Wallet::UmFuture, Wallet::Option),
+            UniversalTransferType::OptionToUmFuture => (Wallet::Option, Wallet::UmFuture),
+            UniversalTransferType::MarginToOption => (Wallet::Margin, Wallet::Option),
+            UniversalTransferType::OptionToMargin => (Wallet::Option, Wallet::Margin),
+            UniversalTransferType::FundingToOption => (Wallet::Funding, Wallet::Option),
+            UniversalTransferType::OptionToFunding => (Wallet::Option, Wallet::Funding),
+            UniversalTransferType::MainToPortfolioMargin => (Wallet::Main, Wallet::PortfolioMargin),
+            UniversalTransferType::PortfolioMarginToMain => (Wallet::PortfolioMargin, Wallet::Main),
+        }
+    }
+
+    pub fn from_wallet(self) -> Wallet {
+        self.wallets().0
+    }
+
+    pub fn to_wallet(self) -> Wallet {
+        self.wallets().1
+    }
+
+    /// The route between two wallets, if assets can be moved directly between them
+    pub fn route(from: Wallet, to: Wallet) -> Option<UniversalTransferType> {
+        Self::ALL
+            .iter()
+            .copied()
+            .find(|route| route.wallets() == (from, to))
+    }
+
+    /// Whether the route needs the isolated margin symbols to transfer from or to
+    pub fn is_isolated(self) -> bool {
+        let (from, to) = self.wallets();
+        from == Wallet::IsolatedMargin || to == Wallet::IsolatedMargin
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransfer {
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    pub asset: String,
+    pub amount: f64,
+    /// Isolated margin symbol transferred from, required when leaving isolated margin
+    pub from_symbol: Option<String>,
+    /// Isolated margin symbol transferred to, required when entering isolated margin
+    pub to_symbol: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferRecord {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    pub status: TransactionStatus,
+    pub tran_id: u64,
+    pub timestamp: u64,
+}
+
+/// Universal transfer history query, of a single route, the last 7 days when no time range
+/// is set, which can be at most 30 days
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UniversalTransferQuery {
+    #[serde(rename = "type")]
+    pub transfer_type: UniversalTransferType,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page, from 1
+    pub current: Option<u32>,
+    /// Default 10, max 100
+    pub size: Option<u32>,
+    pub from_symbol: Option<String>,
+    pub to_symbol: Option<String>,
+}
+
+impl UniversalTransferQuery {
+    pub fn new(transfer_type: UniversalTransferType) -> Self {
+        UniversalTransferQuery {
+            transfer_type,
+            start_time: None,
+            end_time: None,
+            current: None,
+            size: None,
+            from_symbol: None,
+            to_symbol: None,
+        }
+    }
+}
+
+/// Deposit history query, the last 90 days when no time range is set, which can be at most
+/// 90 days
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositHistoryQuery {
+    pub coin: Option<String>,
+    pub status: Option<DepositStatus>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Number of records to skip, 0 by default
+    pub offset: Option<u32>,
+    /// Default 1000, max 1000
+    pub limit: Option<u32>,
+    pub tx_id: Option<String>,
+}
+
+/// Withdrawal history query, the last 90 days when no time range is set, which can be at most
+/// 90 days
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawHistoryQuery {
+    pub coin: Option<String>,
+    pub withdraw_order_id: Option<String>,
+    pub status: Option<WithdrawStatus>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Number of records to skip, 0 by default
+    pub offset: Option<u32>,
+    /// Default 1000, max 1000
+    pub limit: Option<u32>,
+}
+
+/// Status of a Simple Earn product
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EarnProductStatus {
+    Preheating,
+    InProgress,
+    Purchasing,
+    Ended,
+    #[serde(other)]
+    Other,
+}
+
+/// A Simple Earn flexible product, redeemable at any time
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleProduct {
+    pub product_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    /// Annual percentage rate of each tier, such as "0-5BTC"
+    #[serde(default)]
+    pub tier_annual_percentage_rate: BTreeMap<String, f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub air_drop_percentage_rate: Option<f64>,
+    pub can_purchase: bool,
+    pub can_redeem: bool,
+    pub is_sold_out: bool,
+    pub hot: bool,
+    #[serde(with = "string_or_float")]
+    pub min_purchase_amount: f64,
+    pub subscription_start_time: u64,
+    pub status: EarnProductStatus,
+}
+
+impl FlexibleProduct {
+    /// Whether a subscription of `amount` would be accepted, the personal quota aside
+    pub fn accepts(&self, amount: f64) -> bool {
+        self.can_purchase
+            && !self.is_sold_out
+            && self.status == EarnProductStatus::Purchasing
+            && amount >= self.min_purchase_amount
+    }
+}
+
+/// A Simple Earn locked product, its projects each lock the asset for a duration
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProduct {
+    pub project_id: String,
+    pub detail: LockedProductDetail,
+    pub quota: LockedProductQuota,
+}
+
+impl LockedProduct {
+    /// Whether a subscription of `amount` would be accepted, the personal quota aside
+    pub fn accepts(&self, amount: f64) -> bool {
+        !self.detail.is_sold_out
+            && self.detail.status == EarnProductStatus::Purchasing
+            && amount >= self.quota.minimum
+            && amount <= self.quota.total_personal_quota
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductDetail {
+    pub asset: String,
+    pub reward_asset: String,
+    /// In days
+    pub duration: u32,
+    pub renewable: bool,
+    pub is_sold_out: bool,
+    #[serde(with = "string_or_float")]
+    pub apr: f64,
+    pub status: EarnProductStatus,
+    #[serde(with = "string_or_u64")]
+    pub subscription_start_time: u64,
+    #[serde(default)]
+    pub extra_reward_asset: Option<String>,
+    #[serde(rename = "extraRewardAPR", default, with = "string_or_float_opt")]
+    pub extra_reward_apr: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedProductQuota {
+    #[serde(with = "string_or_float")]
+    pub total_personal_quota: f64,
+    #[serde(with = "string_or_float")]
+    pub minimum: f64,
+}
+
+/// Account a subscription is paid from, or a redemption credited to
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EarnAccount {
+    Spot,
+    Fund,
+    All,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnSubscription {
+    pub purchase_id: u64,
+    /// Only set for locked products
+    #[serde(default)]
+    pub position_id: Option<String>,
+    pub success: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnRedemption {
+    pub redeem_id: u64,
+    pub success: bool,
+}
+
+/// Holding in a flexible product
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexiblePosition {
+    pub product_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub latest_annual_percentage_rate: f64,
+    #[serde(default)]
+    pub tier_annual_percentage_rate: BTreeMap<String, f64>,
+    pub can_redeem: bool,
+    #[serde(default, with = "string_or_float_opt")]
+    pub collateral_amount: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub yesterday_real_time_rewards: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cumulative_bonus_rewards: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cumulative_real_time_rewards: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub cumulative_total_rewards: Option<f64>,
+    pub auto_subscribe: bool,
+}
+
+/// Holding in a locked product
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedPosition {
+    pub position_id: u64,
+    pub project_id: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub purchase_time: u64,
+    /// In days
+    #[serde(with = "string_or_float")]
+    pub duration: f64,
+    pub accrual_days: u32,
+    pub reward_asset: String,
+    #[serde(rename = "APY", with = "string_or_float")]
+    pub apy: f64,
+    #[serde(with = "string_or_float")]
+    pub reward_amt: f64,
+    pub next_pay_date: u64,
+    pub rewards_end_date: u64,
+    pub deliver_date: u64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub redeem_amount_early: Option<f64>,
+    pub can_redeem_early: bool,
+    pub auto_subscribe: bool,
+    /// AUTO, NORMAL
+    #[serde(rename = "type")]
+    pub position_type: String,
+    pub status: String,
+}
+
+/// Rewards paid on flexible products
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FlexibleRewardType {
+    Bonus,
+    Realtime,
+    Rewards,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FlexibleReward {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub rewards: f64,
+    pub project_id: String,
+    #[serde(rename = "type")]
+    pub reward_type: String,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct LockedReward {
+    pub position_id: u64,
+    pub time: u64,
+    pub asset: String,
+    /// In days
+    #[serde(with = "string_or_float")]
+    pub lock_period: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PersonalLeftQuota {
+    #[serde(with = "string_or_float")]
+    pub left_personal_quota: f64,
+}
+
+/// Simple Earn Query
+/// filter products, positions and rewards, the products and positions of every asset when not
+/// set
+/// product_id applies to flexible products, project_id and position_id to locked ones
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct EarnQuery {
+    pub asset: Option<String>,
+    pub product_id: Option<String>,
+    pub project_id: Option<String>,
+    pub position_id: Option<u64>,
+    /// Only used by rewards history, at most 3 months apart
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page, from 1
+    pub current: Option<u32>,
+    /// Default 10, max 100
+    pub size: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct RewardsQuery {
+    #[serde(rename = "type")]
+    reward_type: Option<FlexibleRewardType>,
+    asset: Option<String>,
+    product_id: Option<String>,
+    position_id: Option<u64>,
+    start_time: Option<u64>,
+    end_time: Option<u64>,
+    current: Option<u32>,
+    size: Option<u32>,
+}
+
+impl RewardsQuery {
+    fn new(reward_type: Option<FlexibleRewardType>, query: EarnQuery) -> Self {
+        RewardsQuery {
+            reward_type,
+            asset: query.asset,
+            product_id: query.product_id,
+            position_id: query.position_id,
+            start_time: query.start_time,
+            end_time: query.end_time,
+            current: query.current,
+            size: query.size,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SubscriptionRequest {
+    product_id: Option<String>,
+    project_id: Option<String>,
+    amount: f64,
+    auto_subscribe: Option<bool>,
+    source_account: Option<EarnAccount>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct RedemptionRequest {
+    product_id: Option<String>,
+    position_id: Option<u64>,
+    redeem_all: Option<bool>,
+    amount: Option<f64>,
+    dest_account: Option<EarnAccount>,
+}
+
 #[derive(Clone)]
 pub struct Savings {
     pub client: Client,
@@ -156,4 +971,479 @@ impl Savings {
             .get_signed_d("/sapi/v1/capital/deposit/address", request.as_str())
             .await
     }
+
+    /// Withdraw a coin, after checking the request against the limits of `network`, the
+    /// network it is sent on as listed by `get_all_coins`
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, savings::*, config::*};
+    /// let savings: Savings = Binance::new_with_env(&Config::default());
+    /// let withdrawal = tokio_test::block_on(async {
+    ///     let coins = savings.get_all_coins().await?;
+    ///     let coin = coins.iter().find(|c| c.coin == "USDT").unwrap();
+    ///     let network = coin.network_list.iter().find(|n| n.network == "TRX").unwrap();
+    ///     let request = WithdrawRequest {
+    ///         coin: "USDT".into(),
+    ///         network: Some("TRX".into()),
+    ///         address: "TMuA6YqfCeX8EhbfYEg5y7S4DqzSJireY9".into(),
+    ///         amount: 20.0,
+    ///         ..WithdrawRequest::default()
+    ///     };
+    ///     savings.withdraw(request, network).await
+    /// });
+    /// assert!(withdrawal.is_ok(), "{:?}", withdrawal);
+    /// ```
+    pub async fn withdraw(
+        &self,
+        request: WithdrawRequest,
+        network: &Network,
+    ) -> Result<WithdrawResponse> {
+        request.validate(network)?;
+        self.client
+            .post_signed_p(SAPI_V1_CAPITAL_WITHDRAW_APPLY, request, self.recv_window)
+            .await
+    }
+
+    /// Deposit history, newest first, one page of at most `limit` records from `offset`
+    pub async fn deposit_history(&self, query: DepositHistoryQuery) -> Result<Vec<DepositRecord>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_CAPITAL_DEPOSIT_HISTORY,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Withdrawal history, newest first, one page of at most `limit` records from `offset`
+    pub async fn withdraw_history(
+        &self,
+        query: WithdrawHistoryQuery,
+    ) -> Result<Vec<WithdrawRecord>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_CAPITAL_WITHDRAW_HISTORY,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Move an asset between two wallets of the account, from and to the given isolated margin
+    /// symbols for the routes from or to isolated margin
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, savings::*, config::*};
+    /// let savings: Savings = Binance::new_with_env(&Config::default());
+    /// let route = UniversalTransferType::route(Wallet::Main, Wallet::UmFuture).unwrap();
+    /// let transfer = UniversalTransfer {
+    ///     transfer_type: route,
+    ///     asset: "USDT".into(),
+    ///     amount: 100.0,
+    ///     from_symbol: None,
+    ///     to_symbol: None,
+    /// };
+    /// let transaction_id = tokio_test::block_on(savings.universal_transfer(transfer));
+    /// assert!(transaction_id.is_ok(), "{:?}", transaction_id);
+    /// ```
+    pub async fn universal_transfer(&self, transfer: UniversalTransfer) -> Result<TransactionId> {
+        let (from, to) = transfer.transfer_type.wallets();
+        if from == Wallet::IsolatedMargin && transfer.from_symbol.is_none() {
+            return Err(Error::InvalidTransfer(
+                "a symbol is required to transfer from isolated margin".to_string(),
+            ));
+        }
+        if to == Wallet::IsolatedMargin && transfer.to_symbol.is_none() {
+            return Err(Error::InvalidTransfer(
+                "a symbol is required to transfer to isolated margin".to_string(),
+            ));
+        }
+        self.client
+            .post_signed_p(SAPI_V1_ASSET_TRANSFER, transfer, self.recv_window)
+            .await
+    }
+
+    /// Universal transfer history of a route, newest first
+    pub async fn universal_transfers(
+        &self,
+        query: UniversalTransferQuery,
+    ) -> Result<RecordsQueryResult<UniversalTransferRecord>> {
+        self.client
+            .get_signed_p(SAPI_V1_ASSET_TRANSFER, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Simple Earn flexible products
+    pub async fn flexible_products(
+        &self,
+        query: EarnQuery,
+    ) -> Result<RecordsQueryResult<FlexibleProduct>> {
+        self.client
+            .get_signed_p(SAPI_V1_EARN_FLEXIBLE_LIST, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Simple Earn locked products
+    pub async fn locked_products(
+        &self,
+        query: EarnQuery,
+    ) -> Result<RecordsQueryResult<LockedProduct>> {
+        self.client
+            .get_signed_p(SAPI_V1_EARN_LOCKED_LIST, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Subscribe `amount` to a flexible product, paid from the spot account by default
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, savings::*, config::*};
+    /// let savings: Savings = Binance::new_with_env(&Config::default());
+    /// let subscription = tokio_test::block_on(async {
+    ///     let products = savings
+    ///         .flexible_products(EarnQuery {
+    ///             asset: Some("USDT".into()),
+    ///             ..EarnQuery::default()
+    ///         })
+    ///         .await?;
+    ///     let product = products.rows.iter().find(|p| p.accepts(100.0)).unwrap();
+    ///     savings
+    ///         .subscribe_flexible(product.product_id.as_str(), 100.0, None, None)
+    ///         .await
+    /// });
+    /// assert!(subscription.is_ok(), "{:?}", subscription);
+    /// ```
+    pub async fn subscribe_flexible<S>(
+        &self,
+        product_id: S,
+        amount: f64,
+        auto_subscribe: Option<bool>,
+        source_account: Option<EarnAccount>,
+    ) -> Result<EarnSubscription>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                SAPI_V1_EARN_FLEXIBLE_SUBSCRIBE,
+                SubscriptionRequest {
+                    product_id: Some(product_id.into()),
+                    project_id: None,
+                    amount,
+                    auto_subscribe,
+                    source_account,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Subscribe `amount` to a locked product, paid from the spot account by default
+    pub async fn subscribe_locked<S>(
+        &self,
+        project_id: S,
+        amount: f64,
+        auto_subscribe: Option<bool>,
+        source_account: Option<EarnAccount>,
+    ) -> Result<EarnSubscription>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                SAPI_V1_EARN_LOCKED_SUBSCRIBE,
+                SubscriptionRequest {
+                    product_id: None,
+                    project_id: Some(project_id.into()),
+                    amount,
+                    auto_subscribe,
+                    source_account,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Redeem `amount` from a flexible product, everything when not set, to the spot account
+    /// by default
+    pub async fn redeem_flexible<S>(
+        &self,
+        product_id: S,
+        amount: Option<f64>,
+        dest_account: Option<EarnAccount>,
+    ) -> Result<EarnRedemption>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                SAPI_V1_EARN_FLEXIBLE_REDEEM,
+                RedemptionRequest {
+                    product_id: Some(product_id.into()),
+                    position_id: None,
+                    redeem_all: Some(amount.is_none()),
+                    amount,
+                    dest_account,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Redeem a locked position early
+    pub async fn redeem_locked(&self, position_id: u64) -> Result<EarnRedemption> {
+        self.client
+            .post_signed_p(
+                SAPI_V1_EARN_LOCKED_REDEEM,
+                RedemptionRequest {
+                    product_id: None,
+                    position_id: Some(position_id),
+                    redeem_all: None,
+                    amount: None,
+                    dest_account: None,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Holdings in flexible products
+    pub async fn flexible_positions(
+        &self,
+        query: EarnQuery,
+    ) -> Result<RecordsQueryResult<FlexiblePosition>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_EARN_FLEXIBLE_POSITION,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Holdings in locked products
+    pub async fn locked_positions(
+        &self,
+        query: EarnQuery,
+    ) -> Result<RecordsQueryResult<LockedPosition>> {
+        self.client
+            .get_signed_p(SAPI_V1_EARN_LOCKED_POSITION, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Rewards paid on flexible products, the last 7 days when no time range is set
+    pub async fn flexible_rewards(
+        &self,
+        reward_type: FlexibleRewardType,
+        query: EarnQuery,
+    ) -> Result<RecordsQueryResult<FlexibleReward>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_EARN_FLEXIBLE_REWARDS,
+                Some(RewardsQuery::new(Some(reward_type), query)),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Rewards paid on locked products, the last 7 days when no time range is set
+    pub async fn locked_rewards(
+        &self,
+        query: EarnQuery,
+    ) -> Result<RecordsQueryResult<LockedReward>> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_EARN_LOCKED_REWARDS,
+                Some(RewardsQuery::new(None, query)),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Amount the account can still subscribe to a flexible product
+    pub async fn flexible_left_quota<S>(&self, product_id: S) -> Result<PersonalLeftQuota>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("productId".into(), product_id.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(SAPI_V1_EARN_FLEXIBLE_QUOTA, request.as_str())
+            .await
+    }
+
+    /// Amount the account can still subscribe to a locked product
+    pub async fn locked_left_quota<S>(&self, project_id: S) -> Result<PersonalLeftQuota>
+    where
+        S: Into<String>,
+    {
+        let mut parameters = BTreeMap::new();
+        parameters.insert("projectId".into(), project_id.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(SAPI_V1_EARN_LOCKED_QUOTA, request.as_str())
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::api::Binance;
+
+    fn trx_network() -> Network {
+        let network = r#"{"addressRegex":"^T[1-9A-HJ-NP-Za-km-z]{33}$","coin":"USDT",
+            "depositDesc":"","depositEnable":true,"isDefault":false,"memoRegex":"",
+            "minConfirm":1,"name":"Tron (TRC20)","network":"TRX","resetAddressStatus":false,
+            "specialTips":"","unLockConfirm":0,"withdrawDesc":"","withdrawEnable":true,
+            "withdrawFee":"1","withdrawIntegerMultiple":"0.000001","withdrawMax":"10000000",
+            "withdrawMin":"10"}"#;
+        serde_json::from_str(network).unwrap()
+    }
+
+    #[test]
+    fn validates_withdrawals_against_network() {
+        let network = trx_network();
+        let request = WithdrawRequest {
+            coin: "USDT".into(),
+            network: Some("TRX".into()),
+            address: "TMuA6YqfCeX8EhbfYEg5y7S4DqzSJireY9".into(),
+            amount: 20.0,
+            ..WithdrawRequest::default()
+        };
+        assert!(request.validate(&network).is_ok());
+        for amount in [5.0, 20_000_000.0, 20.0000001] {
+            let request = WithdrawRequest {
+                amount,
+                ..request.clone()
+            };
+            assert!(request.validate(&network).is_err(), "{}", amount);
+        }
+        let request = WithdrawRequest {
+            network: Some("ETH".into()),
+            ..request
+        };
+        assert!(request.validate(&network).is_err());
+
+        let btc = Network {
+            withdraw_integer_multiple: Some("0.00000001".into()),
+            withdraw_max: Some(0.0),
+            ..trx_network()
+        };
+        for amount in [123.19413282, 5243.69037652] {
+            let request = WithdrawRequest {
+                network: None,
+                amount,
+                ..request.clone()
+            };
+            assert!(request.validate(&btc).is_ok(), "{}", amount);
+        }
+
+        let disabled = Network {
+            withdraw_enable: false,
+            ..trx_network()
+        };
+        let request = WithdrawRequest {
+            network: None,
+            ..request
+        };
+        assert!(request.validate(&disabled).is_err());
+    }
+
+    #[test]
+    fn decodes_transfer_statuses() {
+        let deposits = r#"[{"id":"769800519366885376","amount":"0.001","coin":"BNB",
+            "network":"BNB","status":1,"address":"bnb136ns6lfw4zs5hg4n85vdthaad7hq5m4gtkgf23",
+            "addressTag":"101764890","txId":"98A3EA560C6B3336D348B6C83F0F95ECE4F1F5919E94BD",
+            "insertTime":1661493146000,"transferType":0,"confirmTimes":"1/1",
+            "unlockConfirm":0,"walletType":0},{"id":"769754833590042625","amount":"0.5",
+            "coin":"IOTA","network":"IOTA","status":6,"address":"SIZ9VLMHWATXKV99LH99CIGFJ",
+            "addressTag":"","txId":"ESBFVQUTPIWQNJSPXFNHNYHSQNTGKRVKPRABQWTAXCDWOAKDKYWP",
+            "insertTime":1599620082000,"transferType":0,"confirmTimes":"1/1",
+            "unlockConfirm":0,"walletType":0}]"#;
+        let deposits: Vec<DepositRecord> = serde_json::from_str(deposits).unwrap();
+        assert_eq!(deposits[0].status, DepositStatus::Success);
+        assert_eq!(deposits[1].status, DepositStatus::CreditedCannotWithdraw);
+
+        let withdrawals = r#"[{"id":"b6ae22b3aa844210a7041aee7589627c","amount":"8.91000000",
+            "transactionFee":"0.004","coin":"USDT","status":6,
+            "address":"0x94df8b352de7f46f64b01d3666bf6e936e44ce60",
+            "txId":"0xb5ef8c13b968a406cc62a93a8bd80f9e9a906ef1b3fcf20a2e48573c17659268",
+            "applyTime":"2019-10-12 11:12:02","network":"ETH","transferType":0,
+            "withdrawOrderId":"WITHDRAWtest123","info":"The address is not valid.",
+            "confirmNo":3,"walletType":1,"txKey":"","completeTime":"2023-03-23 16:52:41"},
+            {"id":"156ec387f49b41df8724fa744fa82719","amount":"0.00150000",
+            "transactionFee":"0.004","coin":"BTC","status":9,
+            "address":"1FZdVHtiBqMrWdjPyRPULCUceZPJ2WLCsB","applyTime":"2019-09-24 12:43:45",
+            "network":"BTC","transferType":0,"walletType":0}]"#;
+        let withdrawals: Vec<WithdrawRecord> = serde_json::from_str(withdrawals).unwrap();
+        assert_eq!(withdrawals[0].status, WithdrawStatus::Completed);
+        assert_eq!(withdrawals[1].status, WithdrawStatus::Other(9));
+        assert_eq!(withdrawals[1].tx_id, None);
+        assert_eq!(u8::from(WithdrawStatus::Completed), 6);
+    }
+
+    #[test]
+    fn checks_product_subscriptions() {
+        let products = r#"{"rows":[{"asset":"BTC","latestAnnualPercentageRate":"0.00003",
+            "tierAnnualPercentageRate":{"0-5BTC":0.05,"5-10BTC":0.03},
+            "airDropPercentageRate":"0.05","canPurchase":true,"canRedeem":true,
+            "isSoldOut":false,"hot":true,"minPurchaseAmount":"0.01","productId":"BTC001",
+            "subscriptionStartTime":1646182276000,"status":"PURCHASING"}],"total":1}"#;
+        let products: RecordsQueryResult<FlexibleProduct> = serde_json::from_str(products).unwrap();
+        let product = &products.rows[0];
+        assert_eq!(product.tier_annual_percentage_rate["0-5BTC"], 0.05);
+        assert!(product.accepts(0.5));
+        assert!(!product.accepts(0.001));
+
+        let locked = r#"{"projectId":"Axs*90","detail":{"asset":"AXS","rewardAsset":"AXS",
+            "duration":90,"renewable":true,"isSoldOut":true,"apr":"1.2069",
+            "status":"CREATED","subscriptionStartTime":"1646182276000",
+            "extraRewardAsset":"BNB","extraRewardAPR":"0.23"},
+            "quota":{"totalPersonalQuota":"2","minimum":"0.001"}}"#;
+        let locked: LockedProduct = serde_json::from_str(locked).unwrap();
+        assert_eq!(locked.detail.status, EarnProductStatus::Other);
+        assert!(!locked.accepts(1.0));
+    }
+
+    #[test]
+    fn maps_transfer_routes_to_wallets() {
+        let route = UniversalTransferType::route(Wallet::Funding, Wallet::UmFuture);
+        assert_eq!(route, Some(UniversalTransferType::FundingToUmFuture));
+        assert_eq!(
+            serde_json::to_string(&UniversalTransferType::MainToPortfolioMargin).unwrap(),
+            "\"MAIN_PORTFOLIO_MARGIN\""
+        );
+        assert_eq!(
+            UniversalTransferType::route(Wallet::CmFuture, Wallet::Option),
+            None
+        );
+        assert!(UniversalTransferType::IsolatedMarginToIsolatedMargin.is_isolated());
+
+        let records = r#"{"total":2,"rows":[{"asset":"USDT","amount":"1","type":"MAIN_UMFUTURE",
+            "status":"CONFIRMED","tranId":11415955596,"timestamp":1544433328000},
+            {"asset":"USDT","amount":"2","type":"MAIN_UMFUTURE","status":"CONFIRMED",
+            "tranId":11366865406,"timestamp":1544433328000}]}"#;
+        let records: RecordsQueryResult<UniversalTransferRecord> =
+            serde_json::from_str(records).unwrap();
+        assert_eq!(records.rows[1].transfer_type.to_wallet(), Wallet::UmFuture);
+    }
+
+    #[test]
+    fn requires_symbols_of_isolated_transfers() {
+        let savings = Savings::new(None, None);
+        let transfer = UniversalTransfer {
+            transfer_type: UniversalTransferType::MarginToIsolatedMargin,
+            asset: "USDT".into(),
+            amount: 100.0,
+            from_symbol: None,
+            to_symbol: None,
+        };
+        let result = tokio_test::block_on(savings.universal_transfer(transfer));
+        assert!(
+            matches!(result, Err(Error::InvalidTransfer(_))),
+            "{:?}",
+            result
+        );
+    }
 }
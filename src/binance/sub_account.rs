@@ -0,0 +1,653 @@
+use super::client::*;
+use super::errors::*;
+use super::rest_model::*;
+
+static SAPI_V1_SUB_ACCOUNT_VIRTUAL: &str = "/sapi/v1/sub-account/virtualSubAccount";
+static SAPI_V1_SUB_ACCOUNT_LIST: &str = "/sapi/v1/sub-account/list";
+static SAPI_V3_SUB_ACCOUNT_ASSETS: &str = "/sapi/v3/sub-account/assets";
+static SAPI_V1_SUB_ACCOUNT_SPOT_SUMMARY: &str = "/sapi/v1/sub-account/spotSummary";
+static SAPI_V2_SUB_ACCOUNT_FUTURES_SUMMARY: &str = "/sapi/v2/sub-account/futures/accountSummary";
+static SAPI_V1_SUB_ACCOUNT_UNIVERSAL_TRANSFER: &str = "/sapi/v1/sub-account/universalTransfer";
+static SAPI_V1_SUB_ACCOUNT_FUTURES_ENABLE: &str = "/sapi/v1/sub-account/futures/enable";
+static SAPI_V1_SUB_ACCOUNT_MARGIN_ENABLE: &str = "/sapi/v1/sub-account/margin/enable";
+static SAPI_V1_SUB_ACCOUNT_API_IP_RESTRICTION: &str =
+    "/sapi/v1/sub-account/subAccountApi/ipRestriction";
+static SAPI_V2_SUB_ACCOUNT_API_IP_RESTRICTION: &str =
+    "/sapi/v2/sub-account/subAccountApi/ipRestriction";
+static SAPI_V1_SUB_ACCOUNT_API_IP_LIST: &str =
+    "/sapi/v1/sub-account/subAccountApi/ipRestriction/ipList";
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountEmail {
+    /// Generated email of a virtual sub-account
+    pub email: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountInfo {
+    pub email: String,
+    pub is_freeze: bool,
+    pub create_time: u64,
+    #[serde(default)]
+    pub is_managed_sub_account: bool,
+    #[serde(default)]
+    pub is_asset_management_sub_account: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccounts {
+    pub sub_accounts: Vec<SubAccountInfo>,
+}
+
+/// Sub-account list query, every sub-account when not filtered
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountQuery {
+    pub email: Option<String>,
+    pub is_freeze: Option<bool>,
+    /// Page, from 1
+    pub page: Option<u32>,
+    /// Default 1, max 200
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub free: f64,
+    #[serde(with = "string_or_float")]
+    pub locked: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountAssets {
+    pub balances: Vec<SubAccountBalance>,
+}
+
+/// Total spot assets of a sub-account, in BTC
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountSpotAsset {
+    pub email: String,
+    #[serde(with = "string_or_float")]
+    pub total_asset: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotSummary {
+    pub total_count: u32,
+    /// Total spot assets of the master account, in BTC
+    #[serde(with = "string_or_float")]
+    pub master_account_total_asset: f64,
+    #[serde(rename = "spotSubUserAssetBtcVoList")]
+    pub sub_accounts: Vec<SubAccountSpotAsset>,
+}
+
+/// Futures market of a sub-account
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum FuturesType {
+    /// USD-M futures
+    UsdMargined,
+    /// COIN-M futures
+    CoinMargined,
+    /// Futures type this version does not know of
+    Other(u8),
+}
+
+impl From<u8> for FuturesType {
+    fn from(futures_type: u8) -> Self {
+        match futures_type {
+            1 => FuturesType::UsdMargined,
+            2 => FuturesType::CoinMargined,
+            other => FuturesType::Other(other),
+        }
+    }
+}
+
+impl From<FuturesType> for u8 {
+    fn from(futures_type: FuturesType) -> Self {
+        match futures_type {
+            FuturesType::UsdMargined => 1,
+            FuturesType::CoinMargined => 2,
+            FuturesType::Other(other) => other,
+        }
+    }
+}
+
+/// USD-M futures margins of a sub-account, or of all of them, in `asset`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UsdFuturesSummary {
+    /// Only set on the summary of a sub-account
+    #[serde(default)]
+    pub email: Option<String>,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maintenance_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    /// Only set on the summary of all the sub-accounts
+    #[serde(default)]
+    pub sub_account_list: Vec<UsdFuturesSummary>,
+}
+
+/// COIN-M futures balances of a sub-account, in `asset`
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinFuturesSubAccountSummary {
+    pub email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+}
+
+/// COIN-M futures balances of all the sub-accounts, in BTC
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CoinFuturesSummary {
+    pub asset: String,
+    #[serde(rename = "totalMarginBalanceOfBTC", with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(rename = "totalUnrealizedProfitOfBTC", with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(rename = "totalWalletBalanceOfBTC", with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    pub sub_account_list: Vec<CoinFuturesSubAccountSummary>,
+}
+
+/// Futures summary of the sub-accounts, for the futures type it was requested for
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesSummary {
+    #[serde(rename = "futureAccountSummaryResp", default)]
+    pub usd_margined: Option<UsdFuturesSummary>,
+    #[serde(rename = "deliveryAccountSummaryResp", default)]
+    pub coin_margined: Option<CoinFuturesSummary>,
+}
+
+/// Wallet of a master or sub-account a transfer moves assets from or to
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SubAccountWallet {
+    Spot,
+    UsdtFuture,
+    CoinFuture,
+    Margin,
+    IsolatedMargin,
+    /// Wallet this version does not know of, it cannot be transferred from or to
+    #[serde(other, skip_serializing)]
+    Other,
+}
+
+/// Transfer between the master account and a sub-account, or two sub-accounts, an account is
+/// the master account when its email is not set
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfer {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub from_account_type: SubAccountWallet,
+    pub to_account_type: SubAccountWallet,
+    pub client_tran_id: Option<String>,
+    /// Isolated margin symbol, required when transferring from or to isolated margin
+    pub symbol: Option<String>,
+    pub asset: String,
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferId {
+    pub tran_id: u64,
+    #[serde(default)]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferRecord {
+    pub tran_id: u64,
+    pub from_email: String,
+    pub to_email: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub create_time_stamp: u64,
+    pub from_account_type: SubAccountWallet,
+    pub to_account_type: SubAccountWallet,
+    /// SUCCESS, PROCESS, FAILURE, ...
+    pub status: String,
+    #[serde(default)]
+    pub client_tran_id: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransfers {
+    pub result: Vec<SubAccountTransferRecord>,
+    pub total_count: u32,
+}
+
+/// Transfer history query, the last 30 days when no time range is set, which can be at most
+/// 30 days
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SubAccountTransferQuery {
+    pub from_email: Option<String>,
+    pub to_email: Option<String>,
+    pub client_tran_id: Option<String>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Page, from 1
+    pub page: Option<u32>,
+    /// Default 500, max 500
+    pub limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct FuturesEnabled {
+    pub email: String,
+    pub is_futures_enabled: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarginEnabled {
+    pub email: String,
+    pub is_margin_enabled: bool,
+}
+
+/// Trusted IPs of an API key of a sub-account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiIpRestriction {
+    /// Whether the key can only be used from the IPs of the list
+    #[serde(default, with = "string_or_bool")]
+    pub ip_restrict: bool,
+    #[serde(default)]
+    pub ip_list: Vec<String>,
+    pub update_time: u64,
+    pub api_key: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct EmailQuery {
+    email: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct VirtualSubAccountRequest {
+    sub_account_string: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct SummaryQuery {
+    email: Option<String>,
+    futures_type: Option<FuturesType>,
+    page: Option<u32>,
+    size: Option<u32>,
+    limit: Option<u32>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+struct IpRestrictionRequest {
+    email: String,
+    sub_account_api_key: String,
+    /// 1 for unrestricted, 2 for restricted to the trusted IPs
+    status: Option<u8>,
+    /// Comma separated IPs
+    ip_address: Option<String>,
+}
+
+#[derive(Clone)]
+pub struct SubAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl SubAccount {
+    /// Create a virtual sub-account, its email is generated from `name`
+    pub async fn create_virtual<S>(&self, name: S) -> Result<SubAccountEmail>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                SAPI_V1_SUB_ACCOUNT_VIRTUAL,
+                VirtualSubAccountRequest {
+                    sub_account_string: name.into(),
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Sub-accounts of the master account
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, sub_account::*, config::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let query = SubAccountQuery {
+    ///     limit: Some(200),
+    ///     ..SubAccountQuery::default()
+    /// };
+    /// let sub_accounts = tokio_test::block_on(sub_account.list(query));
+    /// assert!(sub_accounts.is_ok(), "{:?}", sub_accounts);
+    /// ```
+    pub async fn list(&self, query: SubAccountQuery) -> Result<SubAccounts> {
+        self.client
+            .get_signed_p(SAPI_V1_SUB_ACCOUNT_LIST, Some(query), self.recv_window)
+            .await
+    }
+
+    /// Spot balances of a sub-account
+    pub async fn assets<S>(&self, email: S) -> Result<SubAccountAssets>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                SAPI_V3_SUB_ACCOUNT_ASSETS,
+                Some(EmailQuery {
+                    email: email.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Total spot assets of the sub-accounts, in BTC, of a single one when `email` is set
+    pub async fn spot_summary(
+        &self,
+        email: Option<String>,
+        page: Option<u32>,
+        size: Option<u32>,
+    ) -> Result<SpotSummary> {
+        let query = SummaryQuery {
+            email,
+            futures_type: None,
+            page,
+            size,
+            limit: None,
+        };
+        self.client
+            .get_signed_p(
+                SAPI_V1_SUB_ACCOUNT_SPOT_SUMMARY,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Futures margins and balances of the sub-accounts
+    pub async fn futures_summary(
+        &self,
+        futures_type: FuturesType,
+        page: Option<u32>,
+        limit: Option<u32>,
+    ) -> Result<FuturesSummary> {
+        let query = SummaryQuery {
+            email: None,
+            futures_type: Some(futures_type),
+            page,
+            size: None,
+            limit,
+        };
+        self.client
+            .get_signed_p(
+                SAPI_V2_SUB_ACCOUNT_FUTURES_SUMMARY,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Transfer between the master account and a sub-account, or two sub-accounts
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, sub_account::*, config::*};
+    /// let sub_account: SubAccount = Binance::new_with_env(&Config::default());
+    /// let transfer = SubAccountTransfer {
+    ///     from_email: None,
+    ///     to_email: Some("strategy_1_virtual@abc.com".into()),
+    ///     from_account_type: SubAccountWallet::Spot,
+    ///     to_account_type: SubAccountWallet::UsdtFuture,
+    ///     client_tran_id: None,
+    ///     symbol: None,
+    ///     asset: "USDT".into(),
+    ///     amount: 100.0,
+    /// };
+    /// let transfer_id = tokio_test::block_on(sub_account.transfer(transfer));
+    /// assert!(transfer_id.is_ok(), "{:?}", transfer_id);
+    /// ```
+    pub async fn transfer(&self, transfer: SubAccountTransfer) -> Result<SubAccountTransferId> {
+        let isolated = transfer.from_account_type == SubAccountWallet::IsolatedMargin
+            || transfer.to_account_type == SubAccountWallet::IsolatedMargin;
+        if isolated && transfer.symbol.is_none() {
+            return Err(Error::InvalidTransfer(
+                "a symbol is required to transfer from or to isolated margin".to_string(),
+            ));
+        }
+        if transfer.from_account_type == SubAccountWallet::Other
+            || transfer.to_account_type == SubAccountWallet::Other
+        {
+            return Err(Error::InvalidTransfer(
+                "cannot transfer from or to an unknown wallet".to_string(),
+            ));
+        }
+        self.client
+            .post_signed_p(
+                SAPI_V1_SUB_ACCOUNT_UNIVERSAL_TRANSFER,
+                transfer,
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// History of the transfers between the master account and the sub-accounts, newest first
+    pub async fn transfers(&self, query: SubAccountTransferQuery) -> Result<SubAccountTransfers> {
+        self.client
+            .get_signed_p(
+                SAPI_V1_SUB_ACCOUNT_UNIVERSAL_TRANSFER,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Enable futures trading for a sub-account
+    pub async fn enable_futures<S>(&self, email: S) -> Result<FuturesEnabled>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                SAPI_V1_SUB_ACCOUNT_FUTURES_ENABLE,
+                EmailQuery {
+                    email: email.into(),
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Enable margin trading for a sub-account
+    pub async fn enable_margin<S>(&self, email: S) -> Result<MarginEnabled>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                SAPI_V1_SUB_ACCOUNT_MARGIN_ENABLE,
+                EmailQuery {
+                    email: email.into(),
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Trusted IPs of an API key of a sub-account
+    pub async fn api_ip_restriction<S, K>(&self, email: S, api_key: K) -> Result<ApiIpRestriction>
+    where
+        S: Into<String>,
+        K: Into<String>,
+    {
+        let query = IpRestrictionRequest {
+            email: email.into(),
+            sub_account_api_key: api_key.into(),
+            status: None,
+            ip_address: None,
+        };
+        self.client
+            .get_signed_p(
+                SAPI_V1_SUB_ACCOUNT_API_IP_RESTRICTION,
+                Some(query),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Restrict an API key of a sub-account to trusted IPs, adding `ip_addresses` to them, or
+    /// allow it from any IP
+    pub async fn set_api_ip_restriction<S, K>(
+        &self,
+        email: S,
+        api_key: K,
+        restrict: bool,
+        ip_addresses: &[&str],
+    ) -> Result<ApiIpRestriction>
+    where
+        S: Into<String>,
+        K: Into<String>,
+    {
+        let request = IpRestrictionRequest {
+            email: email.into(),
+            sub_account_api_key: api_key.into(),
+            status: Some(if restrict { 2 } else { 1 }),
+            ip_address: (!ip_addresses.is_empty()).then(|| ip_addresses.join(",")),
+        };
+        self.client
+            .post_signed_p(
+                SAPI_V2_SUB_ACCOUNT_API_IP_RESTRICTION,
+                request,
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Remove IPs from the trusted IPs of an API key of a sub-account
+    pub async fn delete_api_ips<S, K>(
+        &self,
+        email: S,
+        api_key: K,
+        ip_addresses: &[&str],
+    ) -> Result<ApiIpRestriction>
+    where
+        S: Into<String>,
+        K: Into<String>,
+    {
+        let request = IpRestrictionRequest {
+            email: email.into(),
+            sub_account_api_key: api_key.into(),
+            status: None,
+            ip_address: Some(ip_addresses.join(",")),
+        };
+        self.client
+            .delete_signed_p(SAPI_V1_SUB_ACCOUNT_API_IP_LIST, request, self.recv_window)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_futures_summaries() {
+        let usd = r#"{"futureAccountSummaryResp":{"totalInitialMargin":"9.83137400",
+            "totalMaintenanceMargin":"0.41568700","totalMarginBalance":"23.03235621",
+            "totalOpenOrderInitialMargin":"9.00000000","totalPositionInitialMargin":"0.83137400",
+            "totalUnrealizedProfit":"0.03219710","totalWalletBalance":"22.15879444",
+            "asset":"USD","subAccountList":[{"email":"123@test.com",
+            "totalInitialMargin":"9.00000000","totalMaintenanceMargin":"0.00000000",
+            "totalMarginBalance":"22.12659734","totalOpenOrderInitialMargin":"9.00000000",
+            "totalPositionInitialMargin":"0.00000000","totalUnrealizedProfit":"0.00000000",
+            "totalWalletBalance":"22.12659734","asset":"USD"}]}}"#;
+        let summary: FuturesSummary = serde_json::from_str(usd).unwrap();
+        let usd = summary.usd_margined.unwrap();
+        assert_eq!(
+            usd.sub_account_list[0].email.as_deref(),
+            Some("123@test.com")
+        );
+        assert!(summary.coin_margined.is_none());
+
+        let coin = r#"{"deliveryAccountSummaryResp":{"totalMarginBalanceOfBTC":"25.03221121",
+            "totalUnrealizedProfitOfBTC":"0.12233410","totalWalletBalanceOfBTC":"22.15879444",
+            "asset":"BTC","subAccountList":[{"email":"123@test.com",
+            "totalMarginBalance":"22.12659734","totalUnrealizedProfit":"0",
+            "totalWalletBalance":"22.12659734","asset":"BTC"}]}}"#;
+        let summary: FuturesSummary = serde_json::from_str(coin).unwrap();
+        assert_eq!(
+            summary.coin_margined.unwrap().total_wallet_balance,
+            22.15879444
+        );
+        assert_eq!(
+            serde_json::to_string(&FuturesType::CoinMargined).unwrap(),
+            "2"
+        );
+        let unknown: FuturesType = serde_json::from_str("3").unwrap();
+        assert_eq!(unknown, FuturesType::Other(3));
+        assert_eq!(serde_json::to_string(&unknown).unwrap(), "3");
+    }
+
+    #[test]
+    fn decodes_transfers_and_ip_restrictions() {
+        let transfers = r#"{"result":[{"tranId":92275823339,"fromEmail":"abctest@gmail.com",
+            "toEmail":"deftest@gmail.com","asset":"BNB","amount":"0.01",
+            "createTimeStamp":1640317374000,"fromAccountType":"USDT_FUTURE",
+            "toAccountType":"SPOT","status":"SUCCESS","clientTranId":"test"}],
+            "totalCount":1}"#;
+        let transfers: SubAccountTransfers = serde_json::from_str(transfers).unwrap();
+        assert_eq!(
+            transfers.result[0].from_account_type,
+            SubAccountWallet::UsdtFuture
+        );
+        let unknown: SubAccountWallet = serde_json::from_str("\"FUNDING\"").unwrap();
+        assert_eq!(unknown, SubAccountWallet::Other);
+        assert!(serde_json::to_string(&unknown).is_err());
+
+        let restriction = r#"{"ipRestrict":"true","ipList":["69.210.67.14","8.34.21.10"],
+            "updateTime":1636371437000,"apiKey":"k5V49ldtn4tszj6W3hystegdfvmGbqDzjmkCtpTvC0G74WhK7yd4rfCTo4lShf"}"#;
+        let restriction: ApiIpRestriction = serde_json::from_str(restriction).unwrap();
+        assert!(restriction.ip_restrict);
+        assert_eq!(restriction.ip_list.len(), 2);
+    }
+}
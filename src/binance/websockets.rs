@@ -85,6 +85,297 @@ fn combined_stream(streams: Vec<String>) -> String {
     streams.join("/")
 }
 
+/// Kline intervals accepted by kline streams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KlineInterval {
+    /// Spot only
+    Seconds1,
+    Minutes1,
+    Minutes3,
+    Minutes5,
+    Minutes15,
+    Minutes30,
+    Hours1,
+    Hours2,
+    Hours4,
+    Hours6,
+    Hours8,
+    Hours12,
+    Days1,
+    Days3,
+    Weeks1,
+    Months1,
+}
+
+impl KlineInterval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Seconds1 => "1s",
+            Self::Minutes1 => "1m",
+            Self::Minutes3 => "3m",
+            Self::Minutes5 => "5m",
+            Self::Minutes15 => "15m",
+            Self::Minutes30 => "30m",
+            Self::Hours1 => "1h",
+            Self::Hours2 => "2h",
+            Self::Hours4 => "4h",
+            Self::Hours6 => "6h",
+            Self::Hours8 => "8h",
+            Self::Hours12 => "12h",
+            Self::Days1 => "1d",
+            Self::Days3 => "3d",
+            Self::Weeks1 => "1w",
+            Self::Months1 => "1M",
+        }
+    }
+}
+
+impl std::fmt::Display for KlineInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for KlineInterval {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "1s" => Self::Seconds1,
+            "1m" => Self::Minutes1,
+            "3m" => Self::Minutes3,
+            "5m" => Self::Minutes5,
+            "15m" => Self::Minutes15,
+            "30m" => Self::Minutes30,
+            "1h" => Self::Hours1,
+            "2h" => Self::Hours2,
+            "4h" => Self::Hours4,
+            "6h" => Self::Hours6,
+            "8h" => Self::Hours8,
+            "12h" => Self::Hours12,
+            "1d" => Self::Days1,
+            "3d" => Self::Days3,
+            "1w" => Self::Weeks1,
+            "1M" => Self::Months1,
+            _ => return Err(Error::InvalidPeriod(s.to_string())),
+        })
+    }
+}
+
+/// Number of levels of a partial book depth stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DepthLevels {
+    Five,
+    Ten,
+    Twenty,
+}
+
+impl DepthLevels {
+    pub fn count(&self) -> u16 {
+        match self {
+            Self::Five => 5,
+            Self::Ten => 10,
+            Self::Twenty => 20,
+        }
+    }
+
+    pub fn from_count(count: u16) -> Option<Self> {
+        match count {
+            5 => Some(Self::Five),
+            10 => Some(Self::Ten),
+            20 => Some(Self::Twenty),
+            _ => None,
+        }
+    }
+}
+
+/// Update speed of the book depth streams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DepthSpeed {
+    Ms100,
+    #[default]
+    Ms1000,
+}
+
+/// A market stream, rendered to and parsed from its canonical name (`btcusdt@kline_1m`...)
+///
+/// Symbols are lowercased when rendered and uppercased when parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StreamSpec {
+    AggTrade {
+        symbol: String,
+    },
+    Trade {
+        symbol: String,
+    },
+    Kline {
+        symbol: String,
+        interval: KlineInterval,
+    },
+    Ticker {
+        symbol: String,
+    },
+    AllTickers,
+    MiniTicker {
+        symbol: String,
+    },
+    AllMiniTickers,
+    BookTicker {
+        symbol: String,
+    },
+    AllBookTickers,
+    PartialDepth {
+        symbol: String,
+        levels: DepthLevels,
+        speed: DepthSpeed,
+    },
+    DiffDepth {
+        symbol: String,
+        speed: DepthSpeed,
+    },
+}
+
+/// The payload sent on a stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamEventKind {
+    /// `WebsocketEvent::AggTrade`
+    AggTrade,
+    /// `WebsocketEvent::Trade`
+    Trade,
+    /// `WebsocketEvent::Kline`
+    Kline,
+    /// `WebsocketEvent::DayTicker`
+    DayTicker,
+    /// `WebsocketEvent::DayMiniTicker`
+    DayMiniTicker,
+    /// `BookTickerEvent`
+    BookTicker,
+    /// `OrderBookPartial`
+    PartialDepth,
+    /// `WebsocketEvent::DepthOrderBook`
+    DiffDepth,
+}
+
+impl StreamSpec {
+    pub fn event_kind(&self) -> StreamEventKind {
+        match self {
+            Self::AggTrade { .. } => StreamEventKind::AggTrade,
+            Self::Trade { .. } => StreamEventKind::Trade,
+            Self::Kline { .. } => StreamEventKind::Kline,
+            Self::Ticker { .. } | Self::AllTickers => StreamEventKind::DayTicker,
+            Self::MiniTicker { .. } | Self::AllMiniTickers => StreamEventKind::DayMiniTicker,
+            Self::BookTicker { .. } | Self::AllBookTickers => StreamEventKind::BookTicker,
+            Self::PartialDepth { .. } => StreamEventKind::PartialDepth,
+            Self::DiffDepth { .. } => StreamEventKind::DiffDepth,
+        }
+    }
+
+    /// Whether each message is an array of events rather than a single event
+    pub fn is_array(&self) -> bool {
+        matches!(self, Self::AllTickers | Self::AllMiniTickers)
+    }
+}
+
+impl std::fmt::Display for StreamSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let speed = |speed: &DepthSpeed| match speed {
+            DepthSpeed::Ms100 => "@100ms",
+            DepthSpeed::Ms1000 => "",
+        };
+        match self {
+            Self::AggTrade { symbol } => write!(f, "{}@aggTrade", symbol.to_lowercase()),
+            Self::Trade { symbol } => write!(f, "{}@trade", symbol.to_lowercase()),
+            Self::Kline { symbol, interval } => {
+                write!(f, "{}@kline_{}", symbol.to_lowercase(), interval)
+            }
+            Self::Ticker { symbol } => write!(f, "{}@ticker", symbol.to_lowercase()),
+            Self::AllTickers => f.write_str(all_ticker_stream()),
+            Self::MiniTicker { symbol } => write!(f, "{}@miniTicker", symbol.to_lowercase()),
+            Self::AllMiniTickers => f.write_str(all_mini_ticker_stream()),
+            Self::BookTicker { symbol } => write!(f, "{}@bookTicker", symbol.to_lowercase()),
+            Self::AllBookTickers => f.write_str(all_book_ticker_stream()),
+            Self::PartialDepth {
+                symbol,
+                levels,
+                speed: s,
+            } => write!(
+                f,
+                "{}@depth{}{}",
+                symbol.to_lowercase(),
+                levels.count(),
+                speed(s)
+            ),
+            Self::DiffDepth { symbol, speed: s } => {
+                write!(f, "{}@depth{}", symbol.to_lowercase(), speed(s))
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for StreamSpec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::Msg(format!("invalid stream name {}", s));
+        match s {
+            "!ticker@arr" => return Ok(Self::AllTickers),
+            "!miniTicker@arr" => return Ok(Self::AllMiniTickers),
+            "!bookTicker" => return Ok(Self::AllBookTickers),
+            _ => {}
+        }
+        let (symbol, stream) = s.split_once('@').ok_or_else(invalid)?;
+        if symbol.is_empty() || !symbol.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err(invalid());
+        }
+        let symbol = symbol.to_uppercase();
+        let (stream, speed) = match stream.split_once('@') {
+            Some((stream, "100ms")) => (stream, Some(DepthSpeed::Ms100)),
+            Some((stream, "1000ms")) => (stream, Some(DepthSpeed::Ms1000)),
+            Some(_) => return Err(invalid()),
+            None => (stream, None),
+        };
+        let spec = match stream {
+            "aggTrade" => Self::AggTrade { symbol },
+            "trade" => Self::Trade { symbol },
+            "ticker" => Self::Ticker { symbol },
+            "miniTicker" => Self::MiniTicker { symbol },
+            "bookTicker" => Self::BookTicker { symbol },
+            "depth" => {
+                return Ok(Self::DiffDepth {
+                    symbol,
+                    speed: speed.unwrap_or_default(),
+                })
+            }
+            _ => {
+                if let Some(interval) = stream.strip_prefix("kline_") {
+                    Self::Kline {
+                        symbol,
+                        interval: interval.parse()?,
+                    }
+                } else if let Some(levels) = stream.strip_prefix("depth") {
+                    let levels = levels
+                        .parse()
+                        .ok()
+                        .and_then(DepthLevels::from_count)
+                        .ok_or_else(invalid)?;
+                    return Ok(Self::PartialDepth {
+                        symbol,
+                        levels,
+                        speed: speed.unwrap_or_default(),
+                    });
+                } else {
+                    return Err(invalid());
+                }
+            }
+        };
+        // Only depth streams take an update speed
+        if speed.is_some() {
+            return Err(invalid());
+        }
+        Ok(spec)
+    }
+}
+
 pub struct WebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug> {
     pub socket: Option<(ClientResponse, Framed<BoxedSocket, Codec>)>,
     sender: mpsc::Sender<WE>,
@@ -113,7 +404,22 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> WebSockets<WE> {
     /// Connect to a websocket endpoint
     pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
         let wss: String = format!("{}/{}/{}", self.conf.ws_endpoint, WS_ENDPOINT, endpoint);
+        self.handshake(wss).await
+    }
 
+    /// Connect to several streams at once, their events are wrapped in a `CombinedStreamEvent`
+    /// naming the stream they come from
+    pub async fn connect_multiple_streams(&mut self, endpoints: &[String]) -> Result<()> {
+        let wss: String = format!(
+            "{}/{}?streams={}",
+            self.conf.ws_endpoint,
+            STREAM_ENDPOINT,
+            endpoints.join("/")
+        );
+        self.handshake(wss).await
+    }
+
+    async fn handshake(&mut self, wss: String) -> Result<()> {
         let client = Client::builder()
             .max_http_version(awc::http::Version::HTTP_11)
             .finish();
@@ -181,3 +487,42 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> WebSockets<WE> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_and_parses_stream_names() {
+        let specs = [
+            "btcusdt@aggTrade",
+            "btcusdt@kline_1s",
+            "btcusdt@kline_1M",
+            "!ticker@arr",
+            "btcusdt@depth5",
+            "btcusdt@depth20@100ms",
+            "btcusdt@depth@100ms",
+        ];
+        for name in specs {
+            assert_eq!(name.parse::<StreamSpec>().unwrap().to_string(), name);
+        }
+        let spec = StreamSpec::PartialDepth {
+            symbol: "BTCUSDT".to_string(),
+            levels: DepthLevels::Ten,
+            speed: DepthSpeed::Ms1000,
+        };
+        assert_eq!(
+            "btcusdt@depth10@1000ms".parse::<StreamSpec>().unwrap(),
+            spec
+        );
+        assert_eq!(spec.event_kind(), StreamEventKind::PartialDepth);
+        for name in [
+            "btcusdt@depth7",
+            "btcusdt@kline_2m",
+            "btcusdt@trade@100ms",
+            "btcusdt",
+        ] {
+            assert!(name.parse::<StreamSpec>().is_err());
+        }
+    }
+}
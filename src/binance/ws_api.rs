@@ -0,0 +1,379 @@
+/*!
+Request/response client of the spot websocket API (`ws-api.binance.com`).
+
+Orders are placed, canceled and queried over a single persistent connection instead of a REST
+request each, with the same models as `Account`. Every request gets an id its response is
+matched with, signed requests are signed one by one with the secret key, and every response
+reports the usage of the rate limits it counts against.
+
+The protocol is the same on the futures websocket API, whose client is the same `WsApiClient`
+with the futures endpoint and errors.
+*/
+
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+
+use actix_codec::Framed;
+use awc::error::WsProtocolError;
+use awc::ws::{Codec, Frame, Message};
+use awc::{BoxedSocket, Client, ClientResponse};
+use bytes::Bytes;
+use chrono::Utc;
+use futures_util::{sink::SinkExt as _, stream::StreamExt as _};
+use hex::encode as hex_encode;
+use hmac_sha256::HMAC;
+use log::debug;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::account::{OrderCancellation, OrderRequest, OrderStatusRequest};
+use super::config::Config;
+use super::errors::*;
+use super::rest_model::{AccountInformation, Order, OrderCanceled, Transaction};
+use super::ws_model::{RateLimitUsage, WsApiError, WsApiRequest, WsApiResponse};
+
+static ORDER_PLACE: &str = "order.place";
+static ORDER_CANCEL: &str = "order.cancel";
+static ORDER_STATUS: &str = "order.status";
+static ACCOUNT_STATUS: &str = "account.status";
+
+/// Result of a websocket API request, with the rate limits usage it reported
+#[derive(Debug, Clone)]
+pub struct WsApiResult<T> {
+    pub result: T,
+    pub rate_limits: Vec<RateLimitUsage>,
+}
+
+/// Errors a websocket API client can fail with, those of the spot or of the futures
+pub trait WsApiClientError: From<serde_json::Error> + From<WsProtocolError> {
+    fn msg(msg: String) -> Self;
+
+    /// Error answered by the websocket API
+    fn api(error: WsApiError) -> Self;
+}
+
+impl WsApiClientError for Error {
+    fn msg(msg: String) -> Self {
+        Error::Msg(msg)
+    }
+
+    fn api(error: WsApiError) -> Self {
+        Error::BinanceError {
+            response: error.into(),
+        }
+    }
+}
+
+/// Request/response client of a websocket API endpoint, the spot and the futures ones share the
+/// same protocol and differ by their endpoint and their errors
+pub struct WsApiClient<E: WsApiClientError> {
+    pub socket: Option<(ClientResponse, Framed<BoxedSocket, Codec>)>,
+    api_key: String,
+    secret_key: String,
+    endpoint: String,
+    recv_window: u64,
+    next_id: u64,
+    /// Responses received while waiting for another request
+    pending: HashMap<String, WsApiResponse>,
+    _error: PhantomData<E>,
+}
+
+/// Client of the spot websocket API
+pub type WsApi = WsApiClient<Error>;
+
+/// Parameters of a request, as strings, without the unset ones
+pub(crate) fn to_params<P: serde::Serialize>(
+    payload: &P,
+) -> serde_json::Result<BTreeMap<String, String>> {
+    let mut params = BTreeMap::new();
+    if let Value::Object(map) = serde_json::to_value(payload)? {
+        for (key, value) in map {
+            match value {
+                Value::Null => {}
+                Value::String(s) => {
+                    params.insert(key, s);
+                }
+                // Written as the REST query strings do, without the exponent of small floats
+                Value::Number(n) if n.is_f64() => {
+                    params.insert(key, n.as_f64().unwrap_or_default().to_string());
+                }
+                value => {
+                    params.insert(key, value.to_string());
+                }
+            }
+        }
+    }
+    Ok(params)
+}
+
+/// Add the api key and the timestamp to the parameters, then their signature: the HMAC SHA256
+/// of the parameters sorted by name, as a query string
+pub(crate) fn sign_params(
+    mut params: BTreeMap<String, String>,
+    api_key: &str,
+    secret_key: &str,
+    timestamp: u64,
+) -> BTreeMap<String, String> {
+    params.insert("apiKey".into(), api_key.into());
+    params.insert("timestamp".into(), timestamp.to_string());
+    let payload = params
+        .iter()
+        .map(|(key, value)| format!("{}={}", key, value))
+        .collect::<Vec<_>>()
+        .join("&");
+    let signature = hex_encode(HMAC::mac(payload.as_bytes(), secret_key.as_bytes()));
+    params.insert("signature".into(), signature);
+    params
+}
+
+impl<E: WsApiClientError> WsApiClient<E> {
+    /// New client of the websocket API at `endpoint`, signed requests use `recv_window` unless
+    /// their parameters set it
+    pub fn new<S: Into<String>>(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        endpoint: S,
+        recv_window: u64,
+    ) -> Self {
+        WsApiClient {
+            socket: None,
+            api_key: api_key.unwrap_or_default(),
+            secret_key: secret_key.unwrap_or_default(),
+            endpoint: endpoint.into(),
+            recv_window,
+            next_id: 0,
+            pending: HashMap::new(),
+            _error: PhantomData,
+        }
+    }
+
+    /// Connect to the websocket API
+    pub async fn connect(&mut self) -> std::result::Result<(), E> {
+        let client = Client::builder()
+            .max_http_version(awc::http::Version::HTTP_11)
+            .finish();
+
+        match client.ws(self.endpoint.as_str()).connect().await {
+            Ok(answer) => {
+                self.socket = Some(answer);
+                Ok(())
+            }
+            Err(e) => Err(E::msg(format!("Error during handshake {}", e))),
+        }
+    }
+
+    /// Disconnect from the websocket API
+    pub async fn disconnect(&mut self) -> std::result::Result<(), E> {
+        if let Some((_, ref mut socket)) = self.socket {
+            socket.close().await?;
+            Ok(())
+        } else {
+            Err(E::msg("Not able to close the connection".to_string()))
+        }
+    }
+
+    /// Send a request without waiting for its response, returns the id of the request
+    ///
+    /// Signed requests use the recv window of the parameters, that of the client otherwise.
+    pub async fn send(
+        &mut self,
+        method: &str,
+        mut params: BTreeMap<String, String>,
+        signed: bool,
+    ) -> std::result::Result<String, E> {
+        self.next_id += 1;
+        let id = self.next_id.to_string();
+        if signed {
+            if !params.contains_key("recvWindow") && self.recv_window > 0 {
+                params.insert("recvWindow".into(), self.recv_window.to_string());
+            }
+            let timestamp = Utc::now().timestamp_millis() as u64;
+            params = sign_params(params, &self.api_key, &self.secret_key, timestamp);
+        }
+        let request = serde_json::to_string(&WsApiRequest {
+            id: id.clone(),
+            method: method.into(),
+            params,
+        })?;
+        let Some((_, ref mut socket)) = self.socket else {
+            return Err(E::msg("Not connected to the websocket API".to_string()));
+        };
+        socket.send(Message::Text(request.into())).await?;
+        Ok(id)
+    }
+
+    /// Wait for the response to a request, keeping the responses to other requests for later
+    pub async fn response(&mut self, id: &str) -> std::result::Result<WsApiResponse, E> {
+        if let Some(response) = self.pending.remove(id) {
+            return Ok(response);
+        }
+        loop {
+            let Some((_, ref mut socket)) = self.socket else {
+                return Err(E::msg("Not connected to the websocket API".to_string()));
+            };
+            let message = match socket.next().await {
+                Some(message) => message?,
+                None => return Err(E::msg("Websocket API connection closed".to_string())),
+            };
+            debug!("ws api message - {:?}", message);
+            match message {
+                Frame::Text(msg) => {
+                    let response: WsApiResponse = serde_json::from_slice(&msg)?;
+                    match response.id.as_deref() {
+                        Some(response_id) if response_id == id => return Ok(response),
+                        Some(response_id) => {
+                            self.pending.insert(response_id.to_string(), response);
+                        }
+                        None => {}
+                    }
+                }
+                Frame::Ping(_) => {
+                    socket.send(Message::Pong(Bytes::from_static(b""))).await?;
+                }
+                Frame::Pong(_) | Frame::Binary(_) | Frame::Continuation(_) => {}
+                Frame::Close(e) => {
+                    return Err(E::msg(format!("Disconnected {:?}", e)));
+                }
+            }
+        }
+    }
+
+    /// Send a request and wait for its result
+    pub async fn request<T: DeserializeOwned>(
+        &mut self,
+        method: &str,
+        params: BTreeMap<String, String>,
+        signed: bool,
+    ) -> std::result::Result<WsApiResult<T>, E> {
+        let id = self.send(method, params, signed).await?;
+        let response = self.response(&id).await?;
+        if let Some(error) = response.error {
+            return Err(E::api(error));
+        }
+        let result = response.result.unwrap_or(Value::Null);
+        Ok(WsApiResult {
+            result: serde_json::from_value(result)?,
+            rate_limits: response.rate_limits,
+        })
+    }
+}
+
+impl WsApi {
+    pub fn new_with_options(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        conf: Config,
+    ) -> WsApi {
+        WsApiClient::new(api_key, secret_key, conf.ws_api_endpoint, conf.recv_window)
+    }
+
+    /// Place an order
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance::{api::*, account::*, config::*, rest_model::*, ws_api::*};
+    /// let mut ws_api: WsApi = Binance::new_with_env(&Config::testnet());
+    /// let order = OrderRequest {
+    ///     symbol: "BTCUSDT".into(),
+    ///     side: OrderSide::Buy,
+    ///     order_type: OrderType::Market,
+    ///     quantity: Some(0.001),
+    ///     ..OrderRequest::default()
+    /// };
+    /// let transaction = actix_rt::System::new().block_on(async move {
+    ///     ws_api.connect().await?;
+    ///     ws_api.place_order(order).await
+    /// });
+    /// assert!(transaction.is_ok(), "{:?}", transaction);
+    /// ```
+    pub async fn place_order(&mut self, order: OrderRequest) -> Result<WsApiResult<Transaction>> {
+        let params = to_params(&order)?;
+        self.request(ORDER_PLACE, params, true).await
+    }
+
+    /// Cancel an order
+    pub async fn cancel_order(
+        &mut self,
+        o: OrderCancellation,
+    ) -> Result<WsApiResult<OrderCanceled>> {
+        let params = to_params(&o)?;
+        self.request(ORDER_CANCEL, params, true).await
+    }
+
+    /// Status of an order
+    pub async fn order_status(&mut self, osr: OrderStatusRequest) -> Result<WsApiResult<Order>> {
+        let params = to_params(&osr)?;
+        self.request(ORDER_STATUS, params, true).await
+    }
+
+    /// Balances and permissions of the account
+    pub async fn account_status(&mut self) -> Result<WsApiResult<AccountInformation>> {
+        self.request(ACCOUNT_STATUS, BTreeMap::new(), true).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::rest_model::{OrderSide, OrderType, TimeInForce};
+
+    #[test]
+    fn signs_sorted_parameters() {
+        let order = OrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Sell,
+            order_type: OrderType::Limit,
+            time_in_force: Some(TimeInForce::GTC),
+            quantity: Some(0.01),
+            price: Some(52000.0),
+            recv_window: Some(100),
+            ..OrderRequest::default()
+        };
+        let params = to_params(&order).unwrap();
+        assert_eq!(params.get("recvWindow").map(String::as_str), Some("100"));
+        assert!(!params.contains_key("stopPrice"));
+
+        let order = OrderRequest {
+            symbol: "PEPEUSDT".into(),
+            quantity: Some(1e7),
+            price: Some(0.0000012),
+            ..order
+        };
+        let params = to_params(&order).unwrap();
+        assert_eq!(params.get("price").map(String::as_str), Some("0.0000012"));
+        assert_eq!(params.get("quantity").map(String::as_str), Some("10000000"));
+
+        let mut params = BTreeMap::new();
+        for (key, value) in [
+            ("symbol", "BTCUSDT"),
+            ("side", "SELL"),
+            ("type", "LIMIT"),
+            ("timeInForce", "GTC"),
+            ("quantity", "0.01000000"),
+            ("price", "52000.00"),
+            ("newOrderRespType", "ACK"),
+            ("recvWindow", "100"),
+        ] {
+            params.insert(key.to_string(), value.to_string());
+        }
+        let secret = "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j";
+        let api_key = "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A";
+        let signed = sign_params(params, api_key, secret, 1655716096498);
+        assert_eq!(
+            signed.get("signature").map(String::as_str),
+            Some("b8737effa134e62c6508f952faefc1b81c4a3edaaee132471daf794562dc0ad4")
+        );
+    }
+
+    #[test]
+    fn decodes_responses_with_rate_limits() {
+        let response = r#"{"id":"7","status":400,"error":{"code":-2010,
+            "msg":"Account has insufficient balance for requested action."},
+            "rateLimits":[{"rateLimitType":"ORDERS","interval":"SECOND","intervalNum":10,
+            "limit":50,"count":13},{"rateLimitType":"REQUEST_WEIGHT","interval":"MINUTE",
+            "intervalNum":1,"limit":6000,"count":60}]}"#;
+        let response: WsApiResponse = serde_json::from_str(response).unwrap();
+        assert_eq!(response.id.as_deref(), Some("7"));
+        assert_eq!(response.error.map(|e| e.code), Some(-2010));
+        assert_eq!(response.rate_limits[1].count, 60);
+    }
+}
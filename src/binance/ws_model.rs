@@ -1,5 +1,9 @@
+use std::collections::BTreeMap;
+
+use super::errors::BinanceContentError;
 use super::rest_model::{
-    string_or_float, Asks, Bids, OrderBookPartial, OrderSide, OrderStatus, OrderType, TimeInForce,
+    string_or_float, Asks, Bids, OrderBookPartial, OrderSide, OrderStatus, OrderType,
+    RateLimitInterval, RateLimitType, TimeInForce,
 };
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -475,3 +479,58 @@ pub struct OrderListTransaction {
     #[serde(rename = "c")]
     pub client_order_id: i64,
 }
+
+/// Request of the websocket API, signed requests carry the api key, the timestamp and the
+/// signature in their parameters
+#[derive(Debug, Serialize, Clone)]
+pub struct WsApiRequest {
+    pub id: String,
+    pub method: String,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub params: BTreeMap<String, String>,
+}
+
+/// Response of the websocket API, `result` is set on success and `error` on failure
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct WsApiResponse {
+    pub id: Option<String>,
+    pub status: u16,
+    #[serde(default)]
+    pub result: Option<serde_json::Value>,
+    #[serde(default)]
+    pub error: Option<WsApiError>,
+    /// Usage of the rate limits counting this request
+    #[serde(default)]
+    pub rate_limits: Vec<RateLimitUsage>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WsApiError {
+    pub code: i16,
+    pub msg: String,
+    #[serde(default)]
+    pub data: Option<serde_json::Value>,
+}
+
+impl From<WsApiError> for BinanceContentError {
+    fn from(e: WsApiError) -> Self {
+        BinanceContentError {
+            code: e.code,
+            msg: e.msg,
+            data: e.data,
+        }
+    }
+}
+
+/// Usage of a rate limit after a request
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct RateLimitUsage {
+    pub rate_limit_type: RateLimitType,
+    pub interval: RateLimitInterval,
+    pub interval_num: u32,
+    pub limit: u64,
+    /// Requests, orders or weight used in the current interval
+    pub count: u64,
+}
@@ -0,0 +1,237 @@
+use std::collections::BTreeMap;
+
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::Client;
+use crate::binance_f::rest_model::PairQuery;
+use crate::binance_f::util::*;
+
+pub use crate::binance_f::account::{OrderCancellation, OrderRequest, OrderStatusRequest};
+
+static DAPI_ORDER: &str = "/dapi/v1/order";
+static DAPI_OPEN_ORDERS: &str = "/dapi/v1/openOrders";
+static DAPI_ALL_OPEN_ORDERS: &str = "/dapi/v1/allOpenOrders";
+static DAPI_ALL_ORDERS: &str = "/dapi/v1/allOrders";
+static DAPI_USER_TRADES: &str = "/dapi/v1/userTrades";
+static DAPI_POSITION_RISK: &str = "/dapi/v1/positionRisk";
+static DAPI_BALANCE: &str = "/dapi/v1/balance";
+static DAPI_ACCOUNT: &str = "/dapi/v1/account";
+static DAPI_LEVERAGE: &str = "/dapi/v1/leverage";
+static DAPI_MARGIN_TYPE: &str = "/dapi/v1/marginType";
+static DAPI_POSITION_SIDE_DUAL: &str = "/dapi/v1/positionSide/dual";
+
+#[derive(Clone)]
+pub struct DeliveryAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Orders Query
+/// perform a query on all orders of the account for a symbol or for every symbol of a pair
+/// either symbol or pair must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersQuery {
+    pub symbol: Option<String>,
+    pub pair: Option<String>,
+    pub order_id: Option<u64>,
+    /// The time between start_time and end_time cannot be longer than 7 days
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 50 max 100
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// User Trades Query
+/// perform a query on the trades of the account for a symbol or for every symbol of a pair
+/// either symbol or pair must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTradesQuery {
+    pub symbol: Option<String>,
+    pub pair: Option<String>,
+    /// The time between start_time and end_time cannot be longer than 7 days
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Cannot be sent with pair
+    pub from_id: Option<u64>,
+    /// Default 50 max 1000
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Positions Query
+/// every position of the account when neither margin_asset nor pair is set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionsQuery {
+    pub margin_asset: Option<String>,
+    pub pair: Option<String>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+impl DeliveryAccount {
+    /// Place a validated order, the quantity being a number of contracts
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_d::{api::*, account::*, rest_model::*, config::*};
+    /// let account: DeliveryAccount = BinanceD::new_with_env(&Config::testnet());
+    /// let order = OrderRequest::stop_market("BTCUSD_PERP", OrderSide::Sell, 2.0, 25000.0);
+    /// let order = tokio_test::block_on(account.place_order(order));
+    /// assert!(order.is_ok(), "{:?}", order);
+    /// ```
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Order> {
+        order.validate()?;
+        self.client
+            .post_signed_p(DAPI_ORDER, order, self.recv_window)
+            .await
+    }
+
+    /// Place a cancellation order
+    pub async fn cancel_order(&self, o: OrderCancellation) -> Result<Order> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .delete_signed_p(DAPI_ORDER, &o, recv_window)
+            .await
+    }
+
+    /// Status of an order
+    pub async fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        let recv_window = osr.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(DAPI_ORDER, Some(osr), recv_window)
+            .await
+    }
+
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<CancelAllOpenOrdersResponse>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .delete_signed_p(
+                DAPI_ALL_OPEN_ORDERS,
+                PairQuery {
+                    symbol: symbol.into(),
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Open orders of a symbol
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                DAPI_OPEN_ORDERS,
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// All orders of the account: active, canceled or filled
+    pub async fn get_all_orders(&self, query: OrdersQuery) -> Result<Vec<Order>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(DAPI_ALL_ORDERS, Some(query), recv_window)
+            .await
+    }
+
+    /// Trades of the account
+    pub async fn get_user_trades(&self, query: UserTradesQuery) -> Result<Vec<UserTrade>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(DAPI_USER_TRADES, Some(query), recv_window)
+            .await
+    }
+
+    pub async fn position_information(&self, query: PositionsQuery) -> Result<Vec<Position>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(DAPI_POSITION_RISK, Some(query), recv_window)
+            .await
+    }
+
+    /// Balances of the margin coins
+    pub async fn account_balance(&self) -> Result<Vec<AccountBalance>> {
+        let parameters = BTreeMap::new();
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(DAPI_BALANCE, request.as_str())
+            .await
+    }
+
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        let parameters = BTreeMap::new();
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(DAPI_ACCOUNT, request.as_str())
+            .await
+    }
+
+    pub async fn change_initial_leverage<S>(
+        &self,
+        symbol: S,
+        leverage: u8,
+    ) -> Result<ChangeLeverageResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert("leverage".into(), leverage.to_string());
+
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_d(DAPI_LEVERAGE, request.as_str())
+            .await
+    }
+
+    pub async fn change_margin_type<S>(&self, symbol: S, margin_type: MarginType) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert(
+            "marginType".into(),
+            match margin_type {
+                MarginType::Cross => "CROSSED".into(),
+                MarginType::Isolated => "ISOLATED".into(),
+            },
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(DAPI_MARGIN_TYPE, request.as_str())
+            .await?;
+        Ok(())
+    }
+
+    pub async fn get_position_mode(&self) -> Result<PositionModeResponse> {
+        let parameters = BTreeMap::new();
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(DAPI_POSITION_SIDE_DUAL, request.as_str())
+            .await
+    }
+
+    /// Switch between hedge mode (`true`) and one-way mode on every COIN-M symbol
+    pub async fn change_position_mode(&self, dual_side_position: bool) -> Result<()> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("dualSidePosition".into(), dual_side_position.to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(DAPI_POSITION_SIDE_DUAL, request.as_str())
+            .await?;
+        Ok(())
+    }
+}
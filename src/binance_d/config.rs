@@ -0,0 +1,53 @@
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub delivery_rest_api_endpoint: String,
+    pub delivery_ws_endpoint: String,
+    pub recv_window: u64,
+}
+
+impl Config {
+    /// Configure binance with default production endpoints
+    /// # Examples
+    /// ```
+    /// use exrs::binance_d::config::Config;
+    /// let config = Config::default();
+    /// ```
+    pub fn default() -> Config {
+        Config {
+            delivery_rest_api_endpoint: "https://dapi.binance.com".into(),
+            delivery_ws_endpoint: "wss://dstream.binance.com".into(),
+
+            recv_window: 5000,
+        }
+    }
+
+    /// Configure binance with all testnet endpoints
+    /// # Examples
+    /// ```
+    /// use exrs::binance_d::config::Config;
+    /// let config = Config::testnet();
+    /// ```
+    pub fn testnet() -> Config {
+        Config::default()
+            .set_delivery_rest_api_endpoint("https://testnet.binancefuture.com")
+            .set_delivery_ws_endpoint("wss://dstream.binancefuture.com")
+    }
+
+    pub fn set_delivery_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        delivery_rest_api_endpoint: T,
+    ) -> Self {
+        self.delivery_rest_api_endpoint = delivery_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_delivery_ws_endpoint<T: Into<String>>(mut self, delivery_ws_endpoint: T) -> Self {
+        self.delivery_ws_endpoint = delivery_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+}
@@ -0,0 +1,59 @@
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::*;
+
+#[derive(Clone)]
+pub struct DeliveryGeneral {
+    pub client: Client,
+}
+
+impl DeliveryGeneral {
+    // Test connectivity
+    pub async fn ping(&self) -> Result<String> {
+        self.client.get("/dapi/v1/ping", "").await?;
+        Ok("pong".into())
+    }
+
+    // Check server time
+    pub async fn get_server_time(&self) -> Result<ServerTime> {
+        self.client.get_p("/dapi/v1/time", "").await
+    }
+
+    // Obtain exchange information
+    // - Current exchange trading rules and symbol information
+    pub async fn exchange_info(&self) -> Result<ExchangeInformation> {
+        self.client.get_p("/dapi/v1/exchangeInfo", "").await
+    }
+
+    // Get Symbol information
+    pub async fn get_symbol_info<S>(&self, symbol: S) -> Result<Symbol>
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into();
+        let upper_symbol = symbol.to_uppercase();
+        self.exchange_info()
+            .await?
+            .symbols
+            .into_iter()
+            .find(|item| item.symbol == upper_symbol)
+            .ok_or(Error::UnknownSymbol(symbol))
+    }
+
+    /// Contracts of a pair, perpetual and quarterly, ordered by delivery date
+    pub async fn get_pair_symbols<S>(&self, pair: S) -> Result<Vec<Symbol>>
+    where
+        S: Into<String>,
+    {
+        let pair = pair.into().to_uppercase();
+        let mut symbols: Vec<Symbol> = self
+            .exchange_info()
+            .await?
+            .symbols
+            .into_iter()
+            .filter(|item| item.pair == pair)
+            .collect();
+        symbols.sort_by_key(|s| s.delivery_date);
+        Ok(symbols)
+    }
+}
@@ -0,0 +1,386 @@
+/*!
+## Implemented functionality
+- [x] `Order Book`
+- [x] `Recent Trades List`
+- [x] `Old Trades Lookup (MARKET_DATA)`
+- [x] `Compressed/Aggregate Trades List`
+- [x] `Kline/Candlestick Data`
+- [x] `Index Price and Mark Price`
+- [x] `Get Funding Rate History of Perpetual Futures`
+- [x] `24hr Ticker Price Change Statistics`
+- [x] `Symbol Price Ticker`
+- [x] `Symbol Order Book Ticker`
+- [x] `Open Interest`
+
+Ticker endpoints answer with a list even when queried for a single symbol, the single symbol
+methods return its first item.
+*/
+
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance::klines::*;
+use crate::binance_f::client::*;
+use crate::binance_f::rest_model::{DepthQuery, PairQuery};
+use crate::binance_f::util::*;
+
+static DAPI_DEPTH: &str = "/dapi/v1/depth";
+static DAPI_TRADES: &str = "/dapi/v1/trades";
+static DAPI_HISTORICAL_TRADES: &str = "/dapi/v1/historicalTrades";
+static DAPI_AGG_TRADES: &str = "/dapi/v1/aggTrades";
+static DAPI_KLINES: &str = "/dapi/v1/klines";
+static DAPI_PREMIUM_INDEX: &str = "/dapi/v1/premiumIndex";
+static DAPI_FUNDING_RATE: &str = "/dapi/v1/fundingRate";
+static DAPI_TICKER_24HR: &str = "/dapi/v1/ticker/24hr";
+static DAPI_TICKER_PRICE: &str = "/dapi/v1/ticker/price";
+static DAPI_BOOK_TICKER: &str = "/dapi/v1/ticker/bookTicker";
+static DAPI_OPEN_INTEREST: &str = "/dapi/v1/openInterest";
+
+/// Longest time range a klines request may span
+static KLINES_MAX_SPAN_MILLIS: u64 = 200 * 24 * 60 * 60 * 1000;
+
+#[derive(Clone)]
+pub struct DeliveryMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl Candle for Kline {
+    fn open_time(&self) -> u64 {
+        self.open_time
+    }
+}
+
+fn to_kline(row: &[Value]) -> Kline {
+    Kline {
+        open_time: to_i64(&row[0]) as u64,
+        open: to_f64(&row[1]),
+        high: to_f64(&row[2]),
+        low: to_f64(&row[3]),
+        close: to_f64(&row[4]),
+        volume: to_f64(&row[5]),
+        close_time: to_i64(&row[6]) as u64,
+        base_asset_volume: to_f64(&row[7]),
+        number_of_trades: to_i64(&row[8]) as u64,
+        taker_buy_volume: to_f64(&row[9]),
+        taker_buy_base_asset_volume: to_f64(&row[10]),
+    }
+}
+
+fn symbol_query(symbol: String) -> SymbolOrPairQuery {
+    SymbolOrPairQuery {
+        symbol: Some(symbol),
+        pair: None,
+    }
+}
+
+fn pair_query(pair: String) -> SymbolOrPairQuery {
+    SymbolOrPairQuery {
+        symbol: None,
+        pair: Some(pair),
+    }
+}
+
+impl DeliveryMarket {
+    /// Ticker of a single symbol, sent as a list
+    async fn single<T: DeserializeOwned>(&self, endpoint: &str, symbol: String) -> Result<T> {
+        let items: Vec<T> = self
+            .client
+            .get_d(endpoint, Some(symbol_query(symbol.clone())))
+            .await?;
+        items.into_iter().next().ok_or(Error::UnknownSymbol(symbol))
+    }
+
+    // Order book (Default 500; max 1000)
+    pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBookPartial>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                DAPI_DEPTH,
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+
+    pub async fn get_custom_depth<S>(&self, symbol: S, limit: u16) -> Result<OrderBookPartial>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                DAPI_DEPTH,
+                Some(DepthQuery {
+                    symbol: symbol.into(),
+                    limit,
+                }),
+            )
+            .await
+    }
+
+    /// Get recent trades for a symbol
+    pub async fn get_trades<S>(&self, symbol: S) -> Result<Vec<Trade>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                DAPI_TRADES,
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Get historical trades from `from_id`, the most recent ones when not set
+    pub async fn get_historical_trades<S1, S2, S3>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        limit: S3,
+    ) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u16>>,
+    {
+        self.client
+            .get_with_api_key_d(
+                DAPI_HISTORICAL_TRADES,
+                Some(HistoryQuery {
+                    symbol: symbol.into(),
+                    from_id: from_id.into(),
+                    start_time: None,
+                    end_time: None,
+                    interval: None,
+                    limit: limit.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Get aggregated trades
+    pub async fn get_agg_trades<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        from_id: S2,
+        start_time: S3,
+        end_time: S4,
+        limit: S5,
+    ) -> Result<Vec<AggTrade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u16>>,
+    {
+        self.client
+            .get_d(
+                DAPI_AGG_TRADES,
+                Some(HistoryQuery {
+                    symbol: symbol.into(),
+                    from_id: from_id.into(),
+                    start_time: start_time.into(),
+                    end_time: end_time.into(),
+                    interval: None,
+                    limit: limit.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
+    pub async fn get_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<Vec<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<Option<u16>>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = HistoryQuery {
+            symbol: symbol.into(),
+            from_id: None,
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            interval: Some(interval.into()),
+            limit: limit.into(),
+        };
+        let data: Vec<Vec<Value>> = self.client.get_d(DAPI_KLINES, Some(query)).await?;
+        Ok(data.iter().map(|row| to_kline(row)).collect())
+    }
+
+    /// Download every kline opening between start_time and end_time (ms, inclusive),
+    /// splitting the range in as many requests as needed
+    ///
+    /// A request spans at most 200 days on this endpoint, the chunk size is clamped to it.
+    pub async fn download_klines<S1, S2>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        start_time: u64,
+        end_time: u64,
+        options: DownloadOptions,
+    ) -> Result<KlineDownload<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let symbol = symbol.into();
+        let interval = interval.into();
+        let interval_ms =
+            interval_millis(&interval).ok_or_else(|| Error::InvalidPeriod(interval.clone()))?;
+        let options = DownloadOptions {
+            chunk_size: options
+                .chunk_size
+                .min(KLINES_MAX_SPAN_MILLIS / interval_ms)
+                .max(1),
+            ..options
+        };
+        download(start_time, end_time, interval_ms, options, |start, end| {
+            self.get_klines(
+                symbol.as_str(),
+                interval.as_str(),
+                options.chunk_size as u16,
+                start,
+                end,
+            )
+        })
+        .await
+    }
+
+    /// Mark price, index price and funding of a symbol
+    pub async fn get_premium_index<S>(&self, symbol: S) -> Result<PremiumIndex>
+    where
+        S: Into<String>,
+    {
+        self.single(DAPI_PREMIUM_INDEX, symbol.into()).await
+    }
+
+    /// Mark price, index price and funding of every symbol of a pair
+    pub async fn get_pair_premium_indexes<S>(&self, pair: S) -> Result<Vec<PremiumIndex>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(DAPI_PREMIUM_INDEX, Some(pair_query(pair.into())))
+            .await
+    }
+
+    /// Funding rate history of a perpetual symbol, the oldest first (Default 100; max 1000)
+    pub async fn get_funding_rate<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<Option<u16>>,
+    {
+        self.client
+            .get_d(
+                DAPI_FUNDING_RATE,
+                Some(HistoryQuery {
+                    symbol: symbol.into(),
+                    from_id: None,
+                    start_time: start_time.into(),
+                    end_time: end_time.into(),
+                    interval: None,
+                    limit: limit.into(),
+                }),
+            )
+            .await
+    }
+
+    /// 24hr ticker price change statistics
+    pub async fn get_24h_price_stats<S>(&self, symbol: S) -> Result<PriceStats>
+    where
+        S: Into<String>,
+    {
+        self.single(DAPI_TICKER_24HR, symbol.into()).await
+    }
+
+    /// 24hr ticker price change statistics of every symbol of a pair
+    pub async fn get_pair_24h_price_stats<S>(&self, pair: S) -> Result<Vec<PriceStats>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(DAPI_TICKER_24HR, Some(pair_query(pair.into())))
+            .await
+    }
+
+    /// 24hr ticker price change statistics for all symbols
+    pub async fn get_all_24h_price_stats(&self) -> Result<Vec<PriceStats>> {
+        self.client.get_p(DAPI_TICKER_24HR, "").await
+    }
+
+    /// Latest price for ONE symbol.
+    pub async fn get_price<S>(&self, symbol: S) -> Result<SymbolPrice>
+    where
+        S: Into<String>,
+    {
+        self.single(DAPI_TICKER_PRICE, symbol.into()).await
+    }
+
+    /// Latest price of every symbol of a pair
+    pub async fn get_pair_prices<S>(&self, pair: S) -> Result<Vec<SymbolPrice>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(DAPI_TICKER_PRICE, Some(pair_query(pair.into())))
+            .await
+    }
+
+    /// Latest price for ALL symbols.
+    pub async fn get_all_prices(&self) -> Result<Vec<SymbolPrice>> {
+        self.client.get_p(DAPI_TICKER_PRICE, "").await
+    }
+
+    /// Best price/qty on the order book for ONE symbol
+    pub async fn get_book_ticker<S>(&self, symbol: S) -> Result<BookTicker>
+    where
+        S: Into<String>,
+    {
+        self.single(DAPI_BOOK_TICKER, symbol.into()).await
+    }
+
+    /// Best price/qty on the order book for ALL symbols.
+    pub async fn get_all_book_tickers(&self) -> Result<Vec<BookTicker>> {
+        self.client.get_p(DAPI_BOOK_TICKER, "").await
+    }
+
+    /// Open interest of a symbol, in contracts
+    pub async fn open_interest<S>(&self, symbol: S) -> Result<OpenInterest>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                DAPI_OPEN_INTEREST,
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+}
@@ -0,0 +1,28 @@
+/*!
+COIN-M (delivery) futures, on the dapi endpoints.
+
+Contracts are margined and settled in the base coin, quantities are numbers of contracts whose
+value in the quote currency is the contract size. The client, the errors and the order requests
+are shared with the USD-M futures of `binance_f`.
+*/
+#![deny(
+    unstable_features,
+    unused_must_use,
+    unused_mut,
+    unused_imports,
+    unused_import_braces
+)]
+#![allow(clippy::needless_doctest_main)]
+
+pub use crate::binance_f::errors;
+
+pub mod rest_model;
+pub mod ws_model;
+
+pub mod account;
+pub mod api;
+pub mod config;
+pub mod general;
+pub mod market;
+pub mod userstream;
+pub mod websockets;
@@ -0,0 +1,475 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::binance_f::account::{ContractType, MarginType, PositionSide, WorkingType};
+pub(crate) use crate::binance_f::rest_model::{
+    empty_string_or_float, string_or_bool, string_or_float, string_or_float_opt,
+};
+pub use crate::binance_f::rest_model::{
+    AggTrade, Asks, Bids, Filters, FundingRate, OrderBookPartial, OrderSide, OrderType, RateLimit,
+    ServerTime, Success, TimeInForce, UserDataStream,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub exchange_filters: Vec<String>,
+    pub rate_limits: Vec<RateLimit>,
+    pub server_time: u64,
+    pub symbols: Vec<Symbol>,
+    pub timezone: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Symbol {
+    pub symbol: String,
+    pub pair: String,
+    pub contract_type: String,
+    /// Far in the future for perpetual contracts
+    pub delivery_date: u64,
+    pub onboard_date: u64,
+    pub contract_status: String,
+    /// Value of a contract in the quote asset
+    pub contract_size: u64,
+    pub margin_asset: String,
+    #[serde(with = "string_or_float")]
+    pub maint_margin_percent: f64,
+    #[serde(with = "string_or_float")]
+    pub required_margin_percent: f64,
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub price_precision: u64,
+    pub quantity_precision: u16,
+    pub base_asset_precision: u64,
+    pub quote_precision: u64,
+    pub equal_qty_precision: u16,
+    pub underlying_type: String,
+    pub underlying_sub_type: Vec<String>,
+    #[serde(with = "string_or_float")]
+    pub trigger_protect: f64,
+    pub filters: Vec<Filters>,
+    pub order_types: Vec<String>,
+    pub time_in_force: Vec<String>,
+}
+
+impl Symbol {
+    pub fn is_perpetual(&self) -> bool {
+        self.contract_type == "PERPETUAL"
+    }
+
+    /// Value of `contracts` in the quote asset
+    pub fn notional(&self, contracts: f64) -> f64 {
+        contracts * self.contract_size as f64
+    }
+
+    /// Value of `contracts` in the base (margin) asset at `price`
+    pub fn base_qty(&self, contracts: f64, price: f64) -> f64 {
+        self.notional(contracts) / price
+    }
+
+    /// Whole number of contracts worth at most `notional` in the quote asset
+    pub fn contracts_for_notional(&self, notional: f64) -> f64 {
+        (notional / self.contract_size as f64).floor()
+    }
+
+    /// Whole number of contracts worth at most `base_qty` of the base asset at `price`
+    pub fn contracts_for_base_qty(&self, base_qty: f64, price: f64) -> f64 {
+        self.contracts_for_notional(base_qty * price)
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Kline {
+    pub open_time: u64,
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub close: f64,
+    /// In contracts
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    pub close_time: u64,
+    #[serde(with = "string_or_float")]
+    pub base_asset_volume: f64,
+    pub number_of_trades: u64,
+    /// In contracts
+    #[serde(with = "string_or_float")]
+    pub taker_buy_volume: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_buy_base_asset_volume: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub is_buyer_maker: bool,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    /// In contracts
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub base_qty: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PriceStats {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: f64,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: f64,
+    #[serde(with = "string_or_float")]
+    pub weighted_avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub open_price: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price: f64,
+    /// In contracts
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub base_volume: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_id: u64,
+    pub last_id: u64,
+    pub count: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct SymbolPrice {
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BookTicker {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub bid_price: f64,
+    #[serde(with = "string_or_float")]
+    pub bid_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub ask_price: f64,
+    #[serde(with = "string_or_float")]
+    pub ask_qty: f64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PremiumIndex {
+    pub symbol: String,
+    pub pair: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    /// Only meaningful in the last hour before delivery or funding
+    #[serde(with = "string_or_float")]
+    pub estimated_settle_price: f64,
+    /// Not applicable to delivery contracts
+    #[serde(default, deserialize_with = "empty_string_or_float")]
+    pub last_funding_rate: Option<f64>,
+    /// Not applicable to delivery contracts
+    #[serde(default, deserialize_with = "empty_string_or_float")]
+    pub interest_rate: Option<f64>,
+    /// 0 for delivery contracts
+    pub next_funding_time: u64,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenInterest {
+    pub symbol: String,
+    pub pair: String,
+    /// In contracts
+    #[serde(with = "string_or_float")]
+    pub open_interest: f64,
+    pub contract_type: ContractType,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub client_order_id: String,
+    /// In contracts
+    #[serde(with = "string_or_float")]
+    pub cum_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cum_base: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: String,
+    #[serde(default, with = "string_or_float")]
+    pub stop_price: f64,
+    #[serde(default)]
+    pub close_position: bool,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub orig_type: OrderType,
+    #[serde(default, with = "string_or_float_opt")]
+    pub activate_price: Option<f64>,
+    #[serde(default, with = "string_or_float_opt")]
+    pub price_rate: Option<f64>,
+    /// Only sent by order queries
+    #[serde(default)]
+    pub time: u64,
+    pub update_time: u64,
+    pub working_type: WorkingType,
+    #[serde(default)]
+    pub price_protect: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    pub symbol: String,
+    pub id: u64,
+    pub order_id: u64,
+    pub pair: String,
+    pub side: OrderSide,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    /// In contracts
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: f64,
+    pub margin_asset: String,
+    #[serde(with = "string_or_float")]
+    pub base_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub time: u64,
+    pub position_side: PositionSide,
+    pub buyer: bool,
+    pub maker: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    /// In contracts, negative for short positions in one-way mode
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    /// In the margin asset
+    #[serde(with = "string_or_float", rename = "unRealizedProfit")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub liquidation_price: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    /// Most contracts at the current leverage
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub margin_type: String,
+    #[serde(with = "string_or_float")]
+    pub isolated_margin: f64,
+    #[serde(with = "string_or_bool")]
+    pub is_auto_add_margin: bool,
+    pub position_side: PositionSide,
+    /// In the margin asset
+    #[serde(with = "string_or_float")]
+    pub notional_value: f64,
+    #[serde(default, with = "string_or_float")]
+    pub isolated_wallet: f64,
+    #[serde(default)]
+    pub update_time: u64,
+}
+
+/// Balance of a margin coin
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    pub account_alias: String,
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub balance: f64,
+    #[serde(with = "string_or_float")]
+    pub withdraw_available: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float", rename = "crossUnPnl")]
+    pub cross_unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
+    pub can_deposit: bool,
+    pub can_trade: bool,
+    pub can_withdraw: bool,
+    pub fee_tier: u8,
+    pub update_time: u64,
+}
+
+/// Margin coin of the account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float", rename = "crossUnPnl")]
+    pub cross_unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    pub isolated: bool,
+    pub position_side: PositionSide,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeLeverageResponse {
+    pub leverage: u8,
+    /// Most contracts at this leverage
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    pub symbol: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionModeResponse {
+    pub dual_side_position: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CancelAllOpenOrdersResponse {
+    pub code: i16,
+    pub msg: String,
+}
+
+/// Query on a symbol or on every symbol of a pair
+#[derive(Debug, Serialize, Default, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SymbolOrPairQuery {
+    pub symbol: Option<String>,
+    pub pair: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct HistoryQuery {
+    pub symbol: String,
+    pub from_id: Option<u64>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub interval: Option<String>,
+    pub limit: Option<u16>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn converts_contracts() {
+        let symbol: Symbol = serde_json::from_str(
+            r#"{"symbol":"BTCUSD_231229","pair":"BTCUSD","contractType":"CURRENT_QUARTER",
+            "deliveryDate":1703836800000,"onboardDate":1688112000000,"contractStatus":"TRADING",
+            "contractSize":100,"marginAsset":"BTC","maintMarginPercent":"2.5000",
+            "requiredMarginPercent":"5.0000","baseAsset":"BTC","quoteAsset":"USD",
+            "pricePrecision":1,"quantityPrecision":0,"baseAssetPrecision":8,"quotePrecision":8,
+            "equalQtyPrecision":4,"maxMoveOrderLimit":10000,"triggerProtect":"0.0500",
+            "underlyingType":"COIN","underlyingSubType":[],"filters":[],"orderTypes":["LIMIT"],
+            "timeInForce":["GTC"],"liquidationFee":"0.010000","marketTakeBound":"0.05"}"#,
+        )
+        .unwrap();
+        assert!(!symbol.is_perpetual());
+        assert_eq!(symbol.notional(3.0), 300.0);
+        assert_eq!(symbol.base_qty(5.0, 25000.0), 0.02);
+        assert_eq!(symbol.contracts_for_notional(350.0), 3.0);
+        assert_eq!(symbol.contracts_for_base_qty(0.1, 25000.0), 25.0);
+    }
+}
@@ -0,0 +1,128 @@
+use super::config::*;
+use super::errors::*;
+use crate::binance_f::websockets::FuturesWebSockets;
+
+use actix_codec::Framed;
+use awc::{ws::Codec, BoxedSocket, ClientResponse};
+use local_channel::mpsc;
+use std::sync::atomic::AtomicBool;
+
+pub use crate::binance_f::websockets::WS_ENDPOINT;
+
+pub fn all_ticker_stream() -> &'static str {
+    "!ticker@arr"
+}
+
+pub fn ticker_stream(symbol: &str) -> String {
+    format!("{}@ticker", symbol)
+}
+
+pub fn agg_trade_stream(symbol: &str) -> String {
+    format!("{}@aggTrade", symbol)
+}
+
+/// Index price of a pair, every 3s
+pub fn index_price_stream(pair: &str) -> String {
+    format!("{}@indexPrice", pair)
+}
+
+/// Mark price of a symbol, every 3s
+pub fn mark_price_stream(symbol: &str) -> String {
+    format!("{}@markPrice", symbol)
+}
+
+/// Mark price of every symbol of a pair, every 3s
+pub fn pair_mark_price_stream(pair: &str) -> String {
+    format!("{}@markPrice", pair)
+}
+
+pub fn kline_stream(symbol: &str, interval: &str) -> String {
+    format!("{}@kline_{}", symbol, interval)
+}
+
+/// # Arguments
+///
+/// * `pair`: the market pair
+/// * `contract_type`: perpetual, current_quarter or next_quarter
+/// * `interval`: the kline interval
+pub fn continuous_kline_stream(pair: &str, contract_type: &str, interval: &str) -> String {
+    format!("{}_{}@continuousKline_{}", pair, contract_type, interval)
+}
+
+pub fn book_ticker_stream(symbol: &str) -> String {
+    format!("{}@bookTicker", symbol)
+}
+
+pub fn all_book_ticker_stream() -> &'static str {
+    "!bookTicker"
+}
+
+pub fn all_mini_ticker_stream() -> &'static str {
+    "!miniTicker@arr"
+}
+
+pub fn mini_ticker_stream(symbol: &str) -> String {
+    format!("{}@miniTicker", symbol)
+}
+
+pub fn force_order_stream(symbol: &str) -> String {
+    format!("{}@forceOrder", symbol)
+}
+
+pub fn all_force_order_stream() -> &'static str {
+    "!forceOrder@arr"
+}
+
+/// # Arguments
+///
+/// * `symbol`: the market symbol
+/// * `levels`: 5, 10 or 20
+/// * `update_speed`: 100, 250 or 500
+pub fn partial_book_depth_stream(symbol: &str, levels: u16, update_speed: u16) -> String {
+    format!("{}@depth{}@{}ms", symbol, levels, update_speed)
+}
+
+/// # Arguments
+///
+/// * `symbol`: the market symbol
+/// * `update_speed`: 100, 250 or 500
+pub fn diff_book_depth_stream(symbol: &str, update_speed: u16) -> String {
+    format!("{}@depth@{}ms", symbol, update_speed)
+}
+
+/// Connector to the delivery streams, the futures one on the delivery websocket endpoint
+pub struct DeliveryWebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug> {
+    inner: FuturesWebSockets<WE>,
+}
+
+impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> DeliveryWebSockets<WE> {
+    /// New websocket holder with default configuration
+    pub fn new(sender: mpsc::Sender<WE>) -> DeliveryWebSockets<WE> {
+        Self::new_with_options(sender, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options(sender: mpsc::Sender<WE>, conf: Config) -> DeliveryWebSockets<WE> {
+        DeliveryWebSockets {
+            inner: FuturesWebSockets::new_with_endpoint(sender, conf.delivery_ws_endpoint),
+        }
+    }
+
+    /// Connect to a websocket endpoint
+    pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
+        self.inner.connect(endpoint).await
+    }
+
+    /// Disconnect from the endpoint
+    pub async fn disconnect(&mut self) -> Result<()> {
+        self.inner.disconnect().await
+    }
+
+    pub fn socket(&self) -> &Option<(ClientResponse, Framed<BoxedSocket, Codec>)> {
+        self.inner.socket()
+    }
+
+    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        self.inner.event_loop(running).await
+    }
+}
@@ -0,0 +1,167 @@
+use serde::{Deserialize, Serialize};
+
+use super::rest_model::{string_or_float, string_or_float_opt};
+pub use crate::binance_f::ws_model::{
+    AccountConfigUpdateEvent, AccountUpdateEvent, AggrTradesEvent, BookTickerEvent,
+    ContinuousKlineEvent, DayTickerEvent, DepthOrderBookEvent, KlineEvent, LiquidationEvent,
+    ListenKeyExpiredEvent, MarginCallEvent, MarkPriceEvent, MiniTickerEvent,
+};
+
+/// Market and user data events of the COIN-M streams, quantities are in contracts
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum DeliveryWebsocketEvent {
+    #[serde(alias = "aggTrade")]
+    AggrTrades(AggrTradesEvent),
+
+    #[serde(alias = "indexPriceUpdate")]
+    IndexPrice(IndexPriceEvent),
+
+    #[serde(alias = "markPriceUpdate")]
+    MarkPrice(MarkPriceEvent),
+
+    #[serde(alias = "kline")]
+    Kline(KlineEvent),
+
+    #[serde(alias = "continuous_kline")]
+    ContinuousKline(ContinuousKlineEvent),
+
+    #[serde(alias = "24hrTicker")]
+    DayTicker(DayTickerEvent),
+
+    #[serde(alias = "24hrMiniTicker")]
+    MiniTicker(MiniTickerEvent),
+
+    #[serde(alias = "bookTicker")]
+    BookTicker(BookTickerEvent),
+
+    #[serde(alias = "forceOrder")]
+    Liquidation(LiquidationEvent),
+
+    #[serde(alias = "depthUpdate")]
+    DepthOrderBook(DepthOrderBookEvent),
+
+    #[serde(alias = "MARGIN_CALL")]
+    MarginCall(MarginCallEvent),
+    #[serde(alias = "ACCOUNT_UPDATE")]
+    AccountUpdate(AccountUpdateEvent),
+    #[serde(alias = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(OrderTradeUpdateEvent),
+    #[serde(alias = "ACCOUNT_CONFIG_UPDATE")]
+    AccountConfigUpdate(AccountConfigUpdateEvent),
+    /// The listen key of the user data stream expired, a new one must be requested
+    #[serde(alias = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpiredEvent),
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "i")]
+    pub pair: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub index_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "o")]
+    pub order_trade_update: OrderTradeUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "o")]
+    pub order_type: String,
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+    /// In contracts
+    #[serde(rename = "q", with = "string_or_float")]
+    pub original_quantity: f64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub original_price: f64,
+    #[serde(rename = "ap", with = "string_or_float")]
+    pub average_price: f64,
+    #[serde(rename = "sp", with = "string_or_float")]
+    pub stop_price: f64,
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub order_status: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub order_last_filled_quantity: f64,
+    #[serde(rename = "z", with = "string_or_float")]
+    pub order_filled_accumulated_quantity: f64,
+    #[serde(rename = "L", with = "string_or_float")]
+    pub last_filled_price: f64,
+    #[serde(rename = "ma")]
+    pub margin_asset: String,
+    /// Only sent with trades
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "n", default, with = "string_or_float_opt")]
+    pub commission: Option<f64>,
+    #[serde(rename = "T")]
+    pub order_trade_time: u64,
+    #[serde(rename = "t")]
+    pub trade_id: u64,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub bid_notional: f64,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub ask_notional: f64,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "R")]
+    pub reduce_only: bool,
+    #[serde(rename = "wt")]
+    pub stop_price_working_type: String,
+    #[serde(rename = "ot")]
+    pub original_order_type: String,
+    #[serde(rename = "ps")]
+    pub position_side: String,
+    #[serde(rename = "cp", default)]
+    pub close_position: bool,
+    #[serde(rename = "AP", default, with = "string_or_float_opt")]
+    pub activation_price: Option<f64>,
+    #[serde(rename = "cr", default, with = "string_or_float_opt")]
+    pub callback_rate: Option<f64>,
+    #[serde(rename = "rp", with = "string_or_float")]
+    pub realized_profit: f64,
+    #[serde(rename = "pP", default)]
+    pub price_protect: bool,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_order_trade_update() {
+        let update = r#"{"e":"ORDER_TRADE_UPDATE","E":1591274595442,"T":1591274595453,"i":"SfsR",
+            "o":{"s":"BTCUSD_200925","c":"TEST","S":"SELL","o":"TRAILING_STOP_MARKET","f":"GTC",
+            "q":"2","p":"0","ap":"0","sp":"9103.1","x":"NEW","X":"NEW","i":8888888,"l":"0",
+            "z":"0","L":"0","ma":"BTC","N":"BTC","n":"0","T":1591274595442,"t":0,"rp":"0",
+            "b":"0","a":"0.00021980","m":false,"R":false,"wt":"CONTRACT_PRICE",
+            "ot":"TRAILING_STOP_MARKET","ps":"LONG","cp":false,"AP":"9476.8","cr":"5.0",
+            "pP":false}}"#;
+        let event: DeliveryWebsocketEvent = serde_json::from_str(update).unwrap();
+        let DeliveryWebsocketEvent::OrderTradeUpdate(event) = event else {
+            panic!("unexpected event {:?}", event);
+        };
+        assert_eq!(event.order_trade_update.margin_asset, "BTC");
+        assert_eq!(event.order_trade_update.callback_rate, Some(5.0));
+    }
+}
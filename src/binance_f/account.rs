@@ -1,3 +1,5 @@
+use futures::stream::Stream;
+use serde::de::DeserializeOwned;
 use serde::Serializer;
 use std::collections::BTreeMap;
 use std::fmt;
@@ -8,11 +10,14 @@ use super::rest_model::CancelAllOpenOrdersResponse;
 use super::rest_model::MultiAssetsMarginResponse;
 use super::rest_model::PositionModeResponse;
 use super::rest_model::{
-    AccountBalance, CanceledOrder, ChangeLeverageResponse, OrderType, Position, Transaction,
+    AccountBalance, AccountInformation, AdlQuantile, CanceledOrder, ChangeLeverageResponse,
+    CommissionRate, Income, IncomeType, Order, OrderType, Position, PositionMarginChange,
+    PositionMarginHistory, PositionMarginResponse, Transaction, UserTrade,
 };
 use super::rest_model::{OrderSide, TimeInForce};
 use super::rest_model::{PairAndWindowQuery, PairQuery};
 use super::util::*;
+use crate::binance::pagination::*;
 
 static FAPI_ORDER: &str = "/fapi/v1/order";
 static FAPI_OPEN_ORDERS: &str = "/fapi/v2/openOrders";
@@ -23,6 +28,36 @@ static FAPI_LEVERAGE: &str = "/fapi/v1/leverage";
 static FAPI_POSITION_SIDE_DUAL: &str = "/fapi/v1/positionSide/dual";
 static FAPI_MULTI_ASSETS_MARGIN: &str = "/fapi/v1/multiAssetsMargin";
 static FAPI_ACCOUNT: &str = "/fapi/v2/account";
+static FAPI_ALL_ORDERS: &str = "/fapi/v1/allOrders";
+static FAPI_USER_TRADES: &str = "/fapi/v1/userTrades";
+static FAPI_OPEN_ORDER: &str = "/fapi/v1/openOrder";
+static FAPI_FORCE_ORDERS: &str = "/fapi/v1/forceOrders";
+static FAPI_ADL_QUANTILE: &str = "/fapi/v1/adlQuantile";
+static FAPI_COMMISSION_RATE: &str = "/fapi/v1/commissionRate";
+static FAPI_INCOME: &str = "/fapi/v1/income";
+static FAPI_BATCH_ORDERS: &str = "/fapi/v1/batchOrders";
+static FAPI_MARGIN_TYPE: &str = "/fapi/v1/marginType";
+static FAPI_POSITION_MARGIN: &str = "/fapi/v1/positionMargin";
+static FAPI_POSITION_MARGIN_HISTORY: &str = "/fapi/v1/positionMargin/history";
+
+/// Most orders placed or modified in a single batch
+pub static MAX_BATCH_ORDERS: usize = 5;
+/// Most orders canceled in a single batch
+pub static MAX_BATCH_CANCELLATIONS: usize = 10;
+
+/// Page limits of the allOrders and userTrades endpoints
+static HISTORY_PAGE_LIMITS: PageLimits = PageLimits {
+    page_size: 1000,
+    max_window: 7 * 24 * 60 * 60 * 1000,
+    pause: DEFAULT_PAGE_PAUSE,
+};
+
+/// Page limits of the income endpoint
+static INCOME_PAGE_LIMITS: PageLimits = PageLimits {
+    page_size: 1000,
+    max_window: 7 * 24 * 60 * 60 * 1000,
+    pause: DEFAULT_PAGE_PAUSE,
+};
 
 #[derive(Clone)]
 pub struct FuturesAccount {
@@ -30,7 +65,7 @@ pub struct FuturesAccount {
     pub recv_window: u64,
 }
 
-#[derive(Debug, Serialize, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ContractType {
     Perpetual,
@@ -47,7 +82,7 @@ impl Default for ContractType {
     }
 }
 
-#[derive(Deserialize, Serialize)]
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq, Hash)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum PositionSide {
     Both,
@@ -55,7 +90,63 @@ pub enum PositionSide {
     Short,
 }
 
-#[derive(Deserialize, Serialize)]
+impl std::str::FromStr for PositionSide {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        match s {
+            "BOTH" => Ok(Self::Both),
+            "LONG" => Ok(Self::Long),
+            "SHORT" => Ok(Self::Short),
+            _ => Err(Error::Msg(format!("unknown position side {}", s))),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum MarginType {
+    #[serde(rename = "CROSSED")]
+    Cross,
+    #[serde(rename = "ISOLATED")]
+    Isolated,
+}
+
+impl MarginType {
+    pub(crate) fn parse(margin_type: &str) -> Self {
+        if margin_type.eq_ignore_ascii_case("isolated") {
+            Self::Isolated
+        } else {
+            Self::Cross
+        }
+    }
+}
+
+/// Bounds an isolated position margin is kept in, an adjustment brings it back to `target`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarginBand {
+    pub min: f64,
+    pub target: f64,
+    pub max: f64,
+}
+
+impl MarginBand {
+    /// The change bringing `margin` back to the target, `None` when it is within the band
+    ///
+    /// The amount is rounded to 8 decimals, the precision margin assets are transferred with.
+    pub fn adjustment(&self, margin: f64) -> Option<(PositionMarginChange, f64)> {
+        let round = |amount: f64| (amount * 1e8).round() / 1e8;
+        if margin < self.min {
+            Some((PositionMarginChange::Add, round(self.target - margin)))
+        } else if margin > self.max {
+            Some((PositionMarginChange::Reduce, round(margin - self.target)))
+        } else {
+            None
+        }
+    }
+}
+
+/// Price triggering stop and take profit orders
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
 #[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum WorkingType {
     MarkPrice,
@@ -88,9 +179,13 @@ where
     }
 }
 
-#[derive(Serialize)]
+/// Order Request
+/// build conditional orders with the constructors, which set the parameters each order type
+/// requires, then refine them with the setters
+/// orders are validated before being sent by `FuturesAccount::place_order`
+#[derive(Default, Debug, Serialize, Clone)]
 #[serde(rename_all = "camelCase")]
-struct OrderRequest {
+pub struct OrderRequest {
     pub symbol: String,
     pub side: OrderSide,
     pub position_side: Option<PositionSide>,
@@ -108,6 +203,207 @@ struct OrderRequest {
     pub working_type: Option<WorkingType>,
     #[serde(serialize_with = "serialize_opt_as_uppercase")]
     pub price_protect: Option<bool>,
+    /// A unique id for the order, automatically generated if not sent.
+    pub new_client_order_id: Option<String>,
+}
+
+impl OrderRequest {
+    fn conditional<S: Into<String>>(symbol: S, side: OrderSide, order_type: OrderType) -> Self {
+        Self {
+            symbol: symbol.into(),
+            side,
+            order_type,
+            ..Self::default()
+        }
+    }
+
+    /// Stop limit order, placed at `price` once the trigger price reaches `stop_price`
+    pub fn stop<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: f64,
+        price: f64,
+        stop_price: f64,
+    ) -> Self {
+        Self {
+            qty: Some(qty),
+            price: Some(price),
+            stop_price: Some(stop_price),
+            time_in_force: Some(TimeInForce::GTC),
+            ..Self::conditional(symbol, side, OrderType::Stop)
+        }
+    }
+
+    /// Stop market order, triggered when the trigger price reaches `stop_price`
+    pub fn stop_market<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: f64,
+        stop_price: f64,
+    ) -> Self {
+        Self {
+            qty: Some(qty),
+            stop_price: Some(stop_price),
+            ..Self::conditional(symbol, side, OrderType::StopMarket)
+        }
+    }
+
+    /// Take profit limit order, placed at `price` once the trigger price reaches `stop_price`
+    pub fn take_profit<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: f64,
+        price: f64,
+        stop_price: f64,
+    ) -> Self {
+        Self {
+            qty: Some(qty),
+            price: Some(price),
+            stop_price: Some(stop_price),
+            time_in_force: Some(TimeInForce::GTC),
+            ..Self::conditional(symbol, side, OrderType::TakeProfit)
+        }
+    }
+
+    /// Take profit market order, triggered when the trigger price reaches `stop_price`
+    pub fn take_profit_market<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: f64,
+        stop_price: f64,
+    ) -> Self {
+        Self {
+            qty: Some(qty),
+            stop_price: Some(stop_price),
+            ..Self::conditional(symbol, side, OrderType::TakeProfitMarket)
+        }
+    }
+
+    /// Trailing stop market order, `callback_rate` is in percent, from 0.1 to 10
+    pub fn trailing_stop_market<S: Into<String>>(
+        symbol: S,
+        side: OrderSide,
+        qty: f64,
+        callback_rate: f64,
+    ) -> Self {
+        Self {
+            qty: Some(qty),
+            callback_rate: Some(callback_rate),
+            ..Self::conditional(symbol, side, OrderType::TrailingStopMarket)
+        }
+    }
+
+    /// Position the order applies to, required in hedge mode
+    pub fn position_side(mut self, position_side: PositionSide) -> Self {
+        self.position_side = Some(position_side);
+        self
+    }
+
+    /// Only reduce the position, not accepted in hedge mode
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    /// Close the whole position when triggered, for stop market and take profit market orders
+    pub fn close_position(mut self) -> Self {
+        self.close_position = Some(true);
+        self.qty = None;
+        self
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Price from which a trailing stop starts tracking, the latest price by default
+    pub fn activation_price(mut self, activation_price: f64) -> Self {
+        self.activation_price = Some(activation_price);
+        self
+    }
+
+    /// Price triggering the order, the contract price by default
+    pub fn working_type(mut self, working_type: WorkingType) -> Self {
+        self.working_type = Some(working_type);
+        self
+    }
+
+    pub fn price_protect(mut self, price_protect: bool) -> Self {
+        self.price_protect = Some(price_protect);
+        self
+    }
+
+    pub fn new_client_order_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.new_client_order_id = Some(id.into());
+        self
+    }
+
+    /// Check the parameters required and accepted by the order type
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |msg: &str| {
+            Err(Error::InvalidOrderError {
+                msg: format!("{:?} order on {}: {}", self.order_type, self.symbol, msg),
+            })
+        };
+        let positive = |v: Option<f64>| v.is_none_or(|v| v > 0.0);
+        if !positive(self.qty)
+            || !positive(self.price)
+            || !positive(self.stop_price)
+            || !positive(self.activation_price)
+        {
+            return invalid("quantity and prices must be positive");
+        }
+        let closes = self.close_position == Some(true);
+        match self.order_type {
+            OrderType::Limit if self.price.is_none() || self.time_in_force.is_none() => {
+                return invalid("price and time in force are required")
+            }
+            OrderType::Stop | OrderType::TakeProfit
+                if self.price.is_none() || self.stop_price.is_none() =>
+            {
+                return invalid("price and stop price are required")
+            }
+            OrderType::StopMarket | OrderType::TakeProfitMarket if self.stop_price.is_none() => {
+                return invalid("stop price is required")
+            }
+            OrderType::TrailingStopMarket => match self.callback_rate {
+                Some(rate) if (0.1..=10.0).contains(&rate) => {}
+                _ => return invalid("callback rate must be between 0.1 and 10"),
+            },
+            OrderType::Limit
+            | OrderType::Market
+            | OrderType::Stop
+            | OrderType::TakeProfit
+            | OrderType::StopMarket
+            | OrderType::TakeProfitMarket => {}
+            _ => return invalid("order type is not supported by futures"),
+        }
+        if closes {
+            if !matches!(
+                self.order_type,
+                OrderType::StopMarket | OrderType::TakeProfitMarket
+            ) {
+                return invalid(
+                    "close position is only accepted by stop and take profit market orders",
+                );
+            }
+            if self.qty.is_some() || self.reduce_only.is_some() {
+                return invalid("close position cannot be sent with a quantity or reduce only");
+            }
+        } else if self.qty.is_none() {
+            return invalid("quantity is required");
+        }
+        if self.reduce_only.is_some()
+            && matches!(
+                self.position_side,
+                Some(PositionSide::Long | PositionSide::Short)
+            )
+        {
+            return invalid("reduce only is not accepted in hedge mode");
+        }
+        Ok(())
+    }
 }
 
 /// Order Cancellation Request
@@ -125,6 +421,199 @@ pub struct OrderCancellation {
     pub timestamp: Option<u64>,
 }
 
+/// Order Modification Request
+/// change the price and quantity of an open limit order
+/// either order_id or orig_client_order_id must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderModification {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    /// Must be the side of the order
+    pub side: OrderSide,
+    pub quantity: f64,
+    pub price: f64,
+}
+
+/// Batch Cancellation Request
+/// either order_ids or orig_client_order_ids must be set
+#[derive(Default, Debug, Clone)]
+pub struct BatchCancellation {
+    pub symbol: String,
+    pub order_ids: Vec<u64>,
+    pub orig_client_order_ids: Vec<String>,
+}
+
+/// Orders Query
+/// perform a query on all orders of the account for a single symbol
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrdersQuery {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    /// The time between start_time and end_time cannot be longer than 7 days
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 500 max 1000
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// User Trades Query
+/// perform a query on the trades of the account for a single symbol
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTradesQuery {
+    pub symbol: String,
+    /// The time between start_time and end_time cannot be longer than 7 days
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Cannot be sent with start_time or end_time
+    pub from_id: Option<u64>,
+    /// Default 500 max 1000
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Order Status Request
+/// either order_id (binance side id) or orig_client_order_id (id originally given by the client) must be set
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderStatusRequest {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub orig_client_order_id: Option<String>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AutoCloseType {
+    Liquidation,
+    Adl,
+}
+
+/// Force Orders Query
+/// perform a query on the liquidation and auto-deleveraging orders of the account
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ForceOrdersQuery {
+    pub symbol: Option<String>,
+    /// Both liquidation and adl orders are returned when not set
+    pub auto_close_type: Option<AutoCloseType>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 50 max 100
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Position Margin History Query
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginHistoryQuery {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub change: Option<PositionMarginChange>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 500
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+/// Income Query
+/// perform a query on the income history of the account, the last 7 days by default
+#[derive(Default, Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IncomeQuery {
+    pub symbol: Option<String>,
+    pub income_type: Option<IncomeType>,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    /// Default 100 max 1000
+    pub limit: Option<u32>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+impl Paged for Order {
+    fn page_id(&self) -> u64 {
+        self.order_id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Paged for UserTrade {
+    fn page_id(&self) -> u64 {
+        self.id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+}
+
+impl Paged for Income {
+    fn page_id(&self) -> u64 {
+        self.tran_id
+    }
+
+    fn page_time(&self) -> u64 {
+        self.time
+    }
+}
+
+/// Batch items are sent as json objects of string values
+fn batch_item<S: serde::Serialize>(item: &S) -> Result<serde_json::Value> {
+    let serde_json::Value::Object(fields) = serde_json::to_value(item)? else {
+        return Err(Error::Msg("batch items must be objects".to_string()));
+    };
+    let fields = fields
+        .into_iter()
+        .filter_map(|(key, value)| match value {
+            serde_json::Value::Null => None,
+            serde_json::Value::String(s) => Some((key, serde_json::Value::String(s))),
+            v => Some((key, serde_json::Value::String(v.to_string()))),
+        })
+        .collect();
+    Ok(serde_json::Value::Object(fields))
+}
+
+/// Each item of a batch response is either the expected record or an error
+fn batch_results<T: DeserializeOwned>(data: &str) -> Result<Vec<Result<T>>> {
+    let items: Vec<serde_json::Value> = serde_json::from_str(data)?;
+    Ok(items
+        .into_iter()
+        .map(|item| {
+            if item.get("code").is_some() && item.get("orderId").is_none() {
+                let response: BinanceContentError = serde_json::from_value(item)?;
+                Err(Error::BinanceError { response })
+            } else {
+                Ok(serde_json::from_value(item)?)
+            }
+        })
+        .collect())
+}
+
+fn check_batch_size(len: usize, max: usize) -> Result<()> {
+    if len == 0 || len > max {
+        return Err(Error::InvalidOrderError {
+            msg: format!("a batch holds from 1 to {} orders, got {}", max, len),
+        });
+    }
+    Ok(())
+}
+
 #[derive(Serialize)]
 #[serde(rename_all = "camelCase")]
 struct ChangePositionModeRequest {
@@ -162,6 +651,7 @@ impl FuturesAccount {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            new_client_order_id: None,
         };
         self.post_order(order).await
     }
@@ -189,6 +679,7 @@ impl FuturesAccount {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            new_client_order_id: None,
         };
         self.post_order(order).await
     }
@@ -214,6 +705,7 @@ impl FuturesAccount {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            new_client_order_id: None,
         };
         self.post_order(order).await
     }
@@ -239,10 +731,113 @@ impl FuturesAccount {
             callback_rate: None,
             working_type: None,
             price_protect: None,
+            new_client_order_id: None,
         };
         self.post_order(order).await
     }
 
+    /// Place a validated order, see `OrderRequest` for the conditional order constructors
+    /// # Examples
+    /// ```rust,no_run
+    /// use binance_f::{api::*, account::*, rest_model::*, config::*};
+    /// let account: FuturesAccount = BinanceF::new_with_env(&Config::testnet());
+    /// let order = OrderRequest::trailing_stop_market("BTCUSDT", OrderSide::Sell, 0.01, 1.5)
+    ///     .position_side(PositionSide::Long)
+    ///     .activation_price(45000.0);
+    /// let transaction = tokio_test::block_on(account.place_order(order));
+    /// assert!(transaction.is_ok(), "{:?}", transaction);
+    /// ```
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Transaction> {
+        order.validate()?;
+        self.post_order(order).await
+    }
+
+    /// Place up to `MAX_BATCH_ORDERS` validated orders at once
+    ///
+    /// Every order is validated before the batch is sent, the exchange then accepts or rejects
+    /// each order on its own: the results are in the order of the requests.
+    pub async fn place_batch_orders(
+        &self,
+        orders: Vec<OrderRequest>,
+    ) -> Result<Vec<Result<Transaction>>> {
+        check_batch_size(orders.len(), MAX_BATCH_ORDERS)?;
+        let items = orders
+            .iter()
+            .map(|order| {
+                order.validate()?;
+                batch_item(order)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("batchOrders".into(), to_json_param(&items)?);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let data = self
+            .client
+            .post_signed(FAPI_BATCH_ORDERS, request.as_str())
+            .await?;
+        batch_results(data.as_str())
+    }
+
+    /// Change the price and quantity of an open limit order
+    pub async fn modify_order(&self, modification: OrderModification) -> Result<Transaction> {
+        self.client
+            .put_signed_p(FAPI_ORDER, modification, self.recv_window)
+            .await
+    }
+
+    /// Modify up to `MAX_BATCH_ORDERS` open limit orders at once, the results are in the order
+    /// of the requests
+    pub async fn modify_batch_orders(
+        &self,
+        modifications: Vec<OrderModification>,
+    ) -> Result<Vec<Result<Transaction>>> {
+        check_batch_size(modifications.len(), MAX_BATCH_ORDERS)?;
+        let items = modifications
+            .iter()
+            .map(batch_item)
+            .collect::<Result<Vec<_>>>()?;
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("batchOrders".into(), to_json_param(&items)?);
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let data = self
+            .client
+            .put_signed(FAPI_BATCH_ORDERS, request.as_str())
+            .await?;
+        batch_results(data.as_str())
+    }
+
+    /// Cancel up to `MAX_BATCH_CANCELLATIONS` orders of a symbol at once, the results are in the
+    /// order of the ids
+    pub async fn cancel_batch_orders(
+        &self,
+        cancellation: BatchCancellation,
+    ) -> Result<Vec<Result<CanceledOrder>>> {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), cancellation.symbol);
+        if !cancellation.order_ids.is_empty() {
+            check_batch_size(cancellation.order_ids.len(), MAX_BATCH_CANCELLATIONS)?;
+            parameters.insert(
+                "orderIdList".into(),
+                to_json_param(&cancellation.order_ids)?,
+            );
+        } else {
+            check_batch_size(
+                cancellation.orig_client_order_ids.len(),
+                MAX_BATCH_CANCELLATIONS,
+            )?;
+            parameters.insert(
+                "origClientOrderIdList".into(),
+                to_json_param(&cancellation.orig_client_order_ids)?,
+            );
+        }
+        let request = build_signed_request(parameters, self.recv_window)?;
+        let data = self
+            .client
+            .delete_signed(FAPI_BATCH_ORDERS, request.as_str())
+            .await?;
+        batch_results(data.as_str())
+    }
+
     /// Place a cancellation order
     pub async fn cancel_order(&self, o: OrderCancellation) -> Result<CanceledOrder> {
         let recv_window = o.recv_window.unwrap_or(self.recv_window);
@@ -275,6 +870,15 @@ impl FuturesAccount {
             .await
     }
 
+    /// Balances and positions of the account
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        let parameters = BTreeMap::new();
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(FAPI_ACCOUNT, request.as_str())
+            .await
+    }
+
     pub async fn account(&self) -> Result<serde_json::Value> {
         let parameters = BTreeMap::new();
         let request = build_signed_request(parameters, self.recv_window)?;
@@ -312,6 +916,103 @@ impl FuturesAccount {
         Ok(())
     }
 
+    pub async fn change_margin_type<S>(&self, symbol: S, margin_type: MarginType) -> Result<()>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert(
+            "marginType".into(),
+            match margin_type {
+                MarginType::Cross => "CROSSED".into(),
+                MarginType::Isolated => "ISOLATED".into(),
+            },
+        );
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed(FAPI_MARGIN_TYPE, request.as_str())
+            .await?;
+        Ok(())
+    }
+
+    /// Add margin to or remove margin from an isolated position
+    pub async fn change_position_margin<S>(
+        &self,
+        symbol: S,
+        position_side: PositionSide,
+        change: PositionMarginChange,
+        amount: f64,
+    ) -> Result<PositionMarginResponse>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        parameters.insert(
+            "positionSide".into(),
+            match position_side {
+                PositionSide::Both => "BOTH".into(),
+                PositionSide::Long => "LONG".into(),
+                PositionSide::Short => "SHORT".into(),
+            },
+        );
+        parameters.insert("amount".into(), amount.to_string());
+        parameters.insert("type".into(), u8::from(change).to_string());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .post_signed_d(FAPI_POSITION_MARGIN, request.as_str())
+            .await
+    }
+
+    /// History of the margin changes of isolated positions for a symbol
+    pub async fn get_position_margin_history(
+        &self,
+        query: PositionMarginHistoryQuery,
+    ) -> Result<Vec<PositionMarginHistory>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(FAPI_POSITION_MARGIN_HISTORY, Some(query), recv_window)
+            .await
+    }
+
+    /// Bring the isolated wallet of a position, its margin without the unrealized profit, back to
+    /// the band target when it is outside the band
+    ///
+    /// Returns `None` when the margin is within the band, or when there is no open isolated
+    /// position on this side.
+    pub async fn keep_isolated_margin_in_band<S>(
+        &self,
+        symbol: S,
+        position_side: PositionSide,
+        band: MarginBand,
+    ) -> Result<Option<PositionMarginResponse>>
+    where
+        S: Into<String>,
+    {
+        if !(band.min <= band.target && band.target <= band.max) {
+            return Err(Error::Msg(format!("invalid margin band {:?}", band)));
+        }
+        let symbol = symbol.into();
+        let positions = self.position_information(symbol.as_str()).await?;
+        let position = positions.into_iter().find(|p| {
+            p.position_amount != 0.0
+                && MarginType::parse(&p.margin_type) == MarginType::Isolated
+                && p.position_side.parse::<PositionSide>().ok() == Some(position_side)
+        });
+        // The band applies to the isolated wallet, the margin transfers change it while the
+        // unrealized profit included in the isolated margin moves with the mark price
+        let adjustment =
+            position.and_then(|p| band.adjustment(p.isolated_margin - p.unrealized_profit));
+        match adjustment {
+            Some((change, amount)) => self
+                .change_position_margin(symbol, position_side, change, amount)
+                .await
+                .map(Some),
+            None => Ok(None),
+        }
+    }
+
     pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<CancelAllOpenOrdersResponse>
     where
         S: Into<String>,
@@ -347,7 +1048,7 @@ impl FuturesAccount {
         let parameters = BTreeMap::new();
         let request = build_signed_request(parameters, self.recv_window)?;
         self.client
-            .get_signed_d(FAPI_MULTI_ASSETS_MARGIN, request.as_str())
+            .get_signed_d(FAPI_POSITION_SIDE_DUAL, request.as_str())
             .await
     }
 
@@ -371,4 +1072,237 @@ impl FuturesAccount {
             .await?;
         Ok(())
     }
+
+    /// All orders of the account for a symbol: active, canceled or filled
+    pub async fn get_all_orders(&self, query: OrdersQuery) -> Result<Vec<Order>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(FAPI_ALL_ORDERS, Some(query), recv_window)
+            .await
+    }
+
+    /// Trades of the account for a symbol
+    pub async fn get_user_trades(&self, query: UserTradesQuery) -> Result<Vec<UserTrade>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(FAPI_USER_TRADES, Some(query), recv_window)
+            .await
+    }
+
+    /// Every order of a symbol, paginated transparently from an order id or through a time range
+    pub fn all_orders_stream<S>(
+        &self,
+        symbol: S,
+        cursor: HistoryCursor,
+    ) -> impl Stream<Item = Result<Order>>
+    where
+        S: Into<String>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        paginate(cursor, HISTORY_PAGE_LIMITS, move |page: PageRequest| {
+            let account = account.clone();
+            let query = OrdersQuery {
+                symbol: symbol.clone(),
+                order_id: page.from_id,
+                start_time: page.start_time,
+                end_time: page.end_time,
+                limit: Some(HISTORY_PAGE_LIMITS.page_size as u32),
+                recv_window: None,
+            };
+            async move { account.get_all_orders(query).await }
+        })
+    }
+
+    /// Every trade of a symbol, paginated transparently from a trade id or through a time range
+    pub fn user_trades_stream<S>(
+        &self,
+        symbol: S,
+        cursor: HistoryCursor,
+    ) -> impl Stream<Item = Result<UserTrade>>
+    where
+        S: Into<String>,
+    {
+        let account = self.clone();
+        let symbol = symbol.into();
+        paginate(cursor, HISTORY_PAGE_LIMITS, move |page: PageRequest| {
+            let account = account.clone();
+            let query = UserTradesQuery {
+                symbol: symbol.clone(),
+                start_time: page.start_time,
+                end_time: page.end_time,
+                from_id: page.from_id,
+                limit: Some(HISTORY_PAGE_LIMITS.page_size as u32),
+                recv_window: None,
+            };
+            async move { account.get_user_trades(query).await }
+        })
+    }
+
+    /// Check an order's status
+    pub async fn order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        let recv_window = osr.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(FAPI_ORDER, Some(osr), recv_window)
+            .await
+    }
+
+    /// Check the status of an order that is still open
+    pub async fn open_order_status(&self, osr: OrderStatusRequest) -> Result<Order> {
+        let recv_window = osr.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(FAPI_OPEN_ORDER, Some(osr), recv_window)
+            .await
+    }
+
+    /// Liquidation and auto-deleveraging orders of the account
+    pub async fn get_force_orders(&self, query: ForceOrdersQuery) -> Result<Vec<Order>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(FAPI_FORCE_ORDERS, Some(query), recv_window)
+            .await
+    }
+
+    /// Position in the auto-deleveraging queue of a symbol
+    pub async fn get_adl_quantile<S>(&self, symbol: S) -> Result<AdlQuantile>
+    where
+        S: Into<String>,
+    {
+        let mut parameters: BTreeMap<String, String> = BTreeMap::new();
+        parameters.insert("symbol".into(), symbol.into());
+        let request = build_signed_request(parameters, self.recv_window)?;
+        self.client
+            .get_signed_d(FAPI_ADL_QUANTILE, request.as_str())
+            .await
+    }
+
+    /// Position in the auto-deleveraging queue of every symbol with a position
+    pub async fn get_adl_quantiles(&self) -> Result<Vec<AdlQuantile>> {
+        let request = build_signed_request(BTreeMap::new(), self.recv_window)?;
+        self.client
+            .get_signed_d(FAPI_ADL_QUANTILE, request.as_str())
+            .await
+    }
+
+    /// Maker and taker commission rates of the account for a symbol
+    pub async fn get_commission_rate<S>(&self, symbol: S) -> Result<CommissionRate>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                FAPI_COMMISSION_RATE,
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Income history of the account: realized pnl, funding fees, commissions, transfers...
+    pub async fn get_income(&self, query: IncomeQuery) -> Result<Vec<Income>> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(FAPI_INCOME, Some(query), recv_window)
+            .await
+    }
+
+    /// Every income of the account in a time range, paginated transparently
+    pub fn income_stream(
+        &self,
+        symbol: Option<String>,
+        income_type: Option<IncomeType>,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<Income>> {
+        let account = self.clone();
+        let cursor = HistoryCursor::Between {
+            start_time,
+            end_time,
+        };
+        paginate(cursor, INCOME_PAGE_LIMITS, move |page: PageRequest| {
+            let account = account.clone();
+            let query = IncomeQuery {
+                symbol: symbol.clone(),
+                income_type,
+                start_time: page.start_time,
+                end_time: page.end_time,
+                limit: Some(INCOME_PAGE_LIMITS.page_size as u32),
+                recv_window: None,
+            };
+            async move { account.get_income(query).await }
+        })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validates_conditional_orders() {
+        let stop = OrderRequest::stop_market("BTCUSDT", OrderSide::Sell, 0.01, 30000.0);
+        assert!(stop.clone().validate().is_ok());
+        assert!(stop.clone().close_position().validate().is_ok());
+        assert!(stop.close_position().reduce_only(true).validate().is_err());
+
+        let trailing = OrderRequest::trailing_stop_market("BTCUSDT", OrderSide::Sell, 0.01, 1.5);
+        assert!(trailing.clone().reduce_only(true).validate().is_ok());
+        assert!(trailing
+            .clone()
+            .position_side(PositionSide::Long)
+            .reduce_only(true)
+            .validate()
+            .is_err());
+        assert!(trailing.close_position().validate().is_err());
+
+        let take_profit = OrderRequest::take_profit("BTCUSDT", OrderSide::Buy, 0.01, 0.0, 25000.0);
+        assert!(take_profit.validate().is_err());
+    }
+    #[test]
+    fn decodes_partially_failed_batches() {
+        let data = r#"[
+            {"clientOrderId":"a","cumQty":"0","cumQuote":"0","executedQty":"0","orderId":1,
+             "avgPrice":"0.0","origQty":"0.01","price":"30000","reduceOnly":false,"side":"BUY",
+             "positionSide":"BOTH","status":"NEW","stopPrice":"0","closePosition":false,
+             "symbol":"BTCUSDT","timeInForce":"GTC","type":"LIMIT","origType":"LIMIT",
+             "updateTime":1,"workingType":"CONTRACT_PRICE","priceProtect":false},
+            {"code":-2022,"msg":"ReduceOnly Order is rejected."}
+        ]"#;
+        let results = batch_results::<Transaction>(data).unwrap();
+        assert!(matches!(&results[0], Ok(t) if t.order_id == 1));
+        assert!(
+            matches!(&results[1], Err(Error::BinanceError { response }) if response.code == -2022)
+        );
+
+        let item = batch_item(&OrderRequest::stop_market(
+            "BTCUSDT",
+            OrderSide::Sell,
+            0.01,
+            1.5,
+        ))
+        .unwrap();
+        assert_eq!(item["quantity"], "0.01");
+        assert!(item.get("price").is_none());
+    }
+    #[test]
+    fn margin_band_adjustments() {
+        let band = MarginBand {
+            min: 80.0,
+            target: 100.0,
+            max: 150.0,
+        };
+        assert_eq!(band.adjustment(100.0), None);
+        assert_eq!(
+            band.adjustment(50.0),
+            Some((PositionMarginChange::Add, 50.0))
+        );
+        assert_eq!(
+            band.adjustment(160.0),
+            Some((PositionMarginChange::Reduce, 60.0))
+        );
+        let (_, amount) = band.adjustment(87.123456789 - 10.0).unwrap();
+        assert_eq!(amount.to_string(), "22.87654321");
+    }
 }
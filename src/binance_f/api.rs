@@ -4,6 +4,7 @@ use super::config::Config;
 use super::general::*;
 use super::market::*;
 use super::userstream::*;
+use super::ws_api::FuturesWsApi;
 
 pub trait BinanceF: Sized {
     fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
@@ -92,3 +93,13 @@ impl BinanceF for FuturesUserStream {
         }
     }
 }
+
+impl BinanceF for FuturesWsApi {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> FuturesWsApi {
+        FuturesWsApi::new_with_options(api_key, secret_key, config.clone())
+    }
+}
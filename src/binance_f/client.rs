@@ -113,6 +113,32 @@ impl Client {
         Ok(t)
     }
 
+    pub async fn put_signed(&self, endpoint: &str, request: &str) -> Result<String> {
+        let url = self.sign_request(endpoint, request);
+        let response = self
+            .inner
+            .clone()
+            .put(url.as_str())
+            .headers(self.build_headers(true)?)
+            .send()
+            .await?;
+
+        self.handler(response).await
+    }
+
+    pub async fn put_signed_p<T: de::DeserializeOwned, P: serde::Serialize>(
+        &self,
+        endpoint: &str,
+        payload: P,
+        recv_window: u64,
+    ) -> Result<T> {
+        let request = build_signed_request_p(payload, recv_window)?;
+        let string = self.put_signed(endpoint, &request).await?;
+        let data: &str = string.as_str();
+        let t = from_str(data)?;
+        Ok(t)
+    }
+
     pub async fn delete_signed_p<T: de::DeserializeOwned, P: serde::Serialize>(
         &self,
         endpoint: &str,
@@ -150,6 +176,39 @@ impl Client {
         self.handler(response).await
     }
 
+    /// Unsigned request sending the api key, for market data endpoints
+    pub async fn get_with_api_key(&self, endpoint: &str, request: &str) -> Result<String> {
+        let mut url: String = format!("{}{}", self.host, endpoint);
+        if !request.is_empty() {
+            url.push_str(format!("?{}", request).as_str());
+        }
+
+        let response = self
+            .inner
+            .clone()
+            .get(url.as_str())
+            .headers(self.build_headers(false)?)
+            .send()
+            .await?;
+
+        self.handler(response).await
+    }
+
+    pub async fn get_with_api_key_d<T: DeserializeOwned, S: serde::Serialize>(
+        &self,
+        endpoint: &str,
+        payload: Option<S>,
+    ) -> Result<T> {
+        let req = if let Some(p) = payload {
+            build_request_p(p)?
+        } else {
+            String::new()
+        };
+        let r = self.get_with_api_key(endpoint, req.as_str()).await?;
+        let t = from_str(r.as_str())?;
+        Ok(t)
+    }
+
     pub async fn get_p<T: DeserializeOwned>(&self, endpoint: &str, request: &str) -> Result<T> {
         let r = self.get(endpoint, request).await?;
         let t = from_str(r.as_str())?;
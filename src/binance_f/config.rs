@@ -2,6 +2,8 @@
 pub struct Config {
     pub futures_rest_api_endpoint: String,
     pub futures_ws_endpoint: String,
+    /// Endpoint of the request/response websocket API
+    pub futures_ws_api_endpoint: String,
     pub recv_window: u64,
 }
 
@@ -16,6 +18,7 @@ impl Config {
         Config {
             futures_rest_api_endpoint: "https://fapi.binance.com".into(),
             futures_ws_endpoint: "wss://fstream.binance.com".into(),
+            futures_ws_api_endpoint: "wss://ws-fapi.binance.com/ws-fapi/v1".into(),
 
             recv_window: 5000,
         }
@@ -31,6 +34,7 @@ impl Config {
         Config::default()
             .set_futures_rest_api_endpoint("https://testnet.binancefuture.com")
             .set_futures_ws_endpoint("wss://testnet.binancefuture.com")
+            .set_futures_ws_api_endpoint("wss://testnet.binancefuture.com/ws-fapi/v1")
     }
 
     pub fn set_futures_rest_api_endpoint<T: Into<String>>(
@@ -46,6 +50,14 @@ impl Config {
         self
     }
 
+    pub fn set_futures_ws_api_endpoint<T: Into<String>>(
+        mut self,
+        futures_ws_api_endpoint: T,
+    ) -> Self {
+        self.futures_ws_api_endpoint = futures_ws_api_endpoint.into();
+        self
+    }
+
     pub fn set_recv_window(mut self, recv_window: u64) -> Self {
         self.recv_window = recv_window;
         self
@@ -66,4 +66,16 @@ pub mod error_messages {
     pub const INVALID_PRICE: &str = "Invalid price.";
 }
 
+impl From<crate::binance::klines::ZeroInterval> for Error {
+    fn from(_: crate::binance::klines::ZeroInterval) -> Self {
+        Error::InvalidPeriod("0".to_string())
+    }
+}
+
+impl From<crate::binance::pagination::PageOverflow> for Error {
+    fn from(e: crate::binance::pagination::PageOverflow) -> Self {
+        Error::Msg(e.to_string())
+    }
+}
+
 pub type Result<T> = core::result::Result<T, Error>;
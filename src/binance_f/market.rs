@@ -6,32 +6,184 @@
 - [x] `Compressed/Aggregate Trades List`
 - [x] `Kline/Candlestick Data`
 - [x] `Mark Price`
-- [ ] `Get Funding Rate History (MARKET_DATA)`
+- [x] `Get Funding Rate History (MARKET_DATA)`
 - [x] `24hr Ticker Price Change Statistics`
 - [x] `Symbol Price Ticker`
 - [x] `Symbol Order Book Ticker`
 - [x] `Get all Liquidation Orders`
 - [x] `Open Interest`
 - [ ] `Notional and Leverage Brackets (MARKET_DATA)`
-- [ ] `Open Interest Statistics (MARKET_DATA)`
+- [x] `Open Interest Statistics (MARKET_DATA)`
 - [ ] `Top Trader Long/Short Ratio (Accounts) (MARKET_DATA)`
 - [ ] `Top Trader Long/Short Ratio (Positions) (MARKET_DATA)`
 - [ ] `Long/Short Ratio (MARKET_DATA)`
-- [ ] `Taker Buy/Sell Volume (MARKET_DATA)`
+- [x] `Taker Buy/Sell Volume (MARKET_DATA)`
 */
 
+use super::account::ContractType;
 use super::client::*;
 use super::errors::*;
 use super::rest_model::*;
 use super::util::*;
+use crate::binance::klines::*;
+use crate::binance::pagination::*;
+use futures::stream::Stream;
 use serde_json::Value;
 
+/// Page limits of the funding rate endpoint, funding happens at most every hour so that a
+/// window never holds more than a page
+static FUNDING_PAGE_LIMITS: PageLimits = PageLimits {
+    page_size: 1000,
+    max_window: 1000 * 60 * 60 * 1000,
+    pause: DEFAULT_PAGE_PAUSE,
+};
+
 #[derive(Clone)]
 pub struct FuturesMarket {
     pub client: Client,
     pub recv_window: u64,
 }
 
+/// Most records in a page of the trading statistics endpoints
+static STATS_PAGE_SIZE: usize = 500;
+
+impl Paged for OpenInterestHistory {
+    fn page_id(&self) -> u64 {
+        self.timestamp
+    }
+
+    fn page_time(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+impl Paged for TakerBuySellVolume {
+    fn page_id(&self) -> u64 {
+        self.timestamp
+    }
+
+    fn page_time(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+impl Paged for Basis {
+    fn page_id(&self) -> u64 {
+        self.timestamp
+    }
+
+    fn page_time(&self) -> u64 {
+        self.timestamp
+    }
+}
+
+/// A window of the trading statistics endpoints holds at most a page of periods
+fn stats_page_limits(period: StatsPeriod) -> PageLimits {
+    PageLimits {
+        page_size: STATS_PAGE_SIZE,
+        max_window: period.millis() * STATS_PAGE_SIZE as u64,
+        pause: DEFAULT_PAGE_PAUSE,
+    }
+}
+
+impl Paged for FundingRate {
+    fn page_id(&self) -> u64 {
+        self.funding_time
+    }
+
+    fn page_time(&self) -> u64 {
+        self.funding_time
+    }
+}
+
+impl Candle for Kline {
+    fn open_time(&self) -> u64 {
+        self.open_time as u64
+    }
+}
+
+impl Candle for MarkPriceKline {
+    fn open_time(&self) -> u64 {
+        self.open_time
+    }
+}
+
+impl Candle for IndexPriceKline {
+    fn open_time(&self) -> u64 {
+        self.open_time
+    }
+}
+
+impl Candle for ContinuousKline {
+    fn open_time(&self) -> u64 {
+        self.open_time
+    }
+}
+
+fn to_kline(row: &[Value]) -> Kline {
+    Kline {
+        open_time: to_i64(&row[0]),
+        open: to_f64(&row[1]),
+        high: to_f64(&row[2]),
+        low: to_f64(&row[3]),
+        close: to_f64(&row[4]),
+        volume: to_f64(&row[5]),
+        close_time: to_i64(&row[6]),
+        quote_asset_volume: to_f64(&row[7]),
+        number_of_trades: to_i64(&row[8]),
+        taker_buy_base_asset_volume: to_f64(&row[9]),
+        taker_buy_quote_asset_volume: to_f64(&row[10]),
+    }
+}
+
+fn to_mark_price_kline(row: &[Value]) -> MarkPriceKline {
+    MarkPriceKline {
+        open_time: to_i64(&row[0]) as u64,
+        open: to_f64(&row[1]),
+        high: to_f64(&row[2]),
+        low: to_f64(&row[3]),
+        close: to_f64(&row[4]),
+        volume: to_f64(&row[5]),
+        close_time: to_i64(&row[6]) as u64,
+        quote_asset_volume: to_f64(&row[7]),
+        number_of_basic_data: to_i64(&row[8]) as u64,
+        taker_buy_base_asset_volume: to_f64(&row[9]),
+        taker_buy_quote_asset_volume: to_f64(&row[10]),
+    }
+}
+
+fn to_index_price_kline(row: &[Value]) -> IndexPriceKline {
+    IndexPriceKline {
+        open_time: to_i64(&row[0]) as u64,
+        open: to_f64(&row[1]),
+        high: to_f64(&row[2]),
+        low: to_f64(&row[3]),
+        close: to_f64(&row[4]),
+        volume: to_f64(&row[5]),
+        close_time: to_i64(&row[6]) as u64,
+        quote_asset_volume: to_f64(&row[7]),
+        number_of_basic_data: to_i64(&row[8]) as u64,
+        taker_buy_base_asset_volume: to_f64(&row[9]),
+        taker_buy_quote_asset_volume: to_f64(&row[10]),
+    }
+}
+
+fn to_continuous_kline(row: &[Value]) -> ContinuousKline {
+    ContinuousKline {
+        open_time: to_i64(&row[0]) as u64,
+        open: to_f64(&row[1]),
+        high: to_f64(&row[2]),
+        low: to_f64(&row[3]),
+        close: to_f64(&row[4]),
+        volume: to_f64(&row[5]),
+        close_time: to_i64(&row[6]) as u64,
+        quote_asset_volume: to_f64(&row[7]),
+        number_of_trades: to_i64(&row[8]) as u64,
+        taker_buy_base_asset_volume: to_f64(&row[9]),
+        taker_buy_quote_asset_volume: to_f64(&row[10]),
+    }
+}
+
 impl FuturesMarket {
     // Order book (Default 100; max 1000)
     pub async fn get_depth<S>(&self, symbol: S) -> Result<OrderBookPartial>
@@ -211,6 +363,9 @@ impl FuturesMarket {
     }
 
     /// Get Taker Long/Short Ratio
+    ///
+    /// The endpoint sends taker buy and sell volumes, which `get_taker_buy_sell_volume` decodes.
+    #[deprecated(note = "use get_taker_buy_sell_volume with a StatsPeriod")]
     pub async fn get_taker_long_short_ratio<S1, S2, S3, S4, S5>(
         &self,
         symbol: S1,
@@ -245,6 +400,218 @@ impl FuturesMarket {
             .await
     }
 
+    /// Open interest history of a symbol, only the last 30 days are available (Default 30; max 500)
+    pub async fn get_open_interest_history<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        period: StatsPeriod,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<OpenInterestHistory>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<u16>,
+    {
+        self.client
+            .get_d(
+                "/futures/data/openInterestHist",
+                Some(HistoryQuery {
+                    start_time: start_time.into(),
+                    end_time: end_time.into(),
+                    from_id: None,
+                    limit: limit.into(),
+                    symbol: symbol.into(),
+                    interval: None,
+                    period: Some(period.as_str().to_string()),
+                }),
+            )
+            .await
+    }
+
+    /// Taker buy and sell volumes of a symbol, only the last 30 days are available
+    /// (Default 30; max 500)
+    pub async fn get_taker_buy_sell_volume<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        period: StatsPeriod,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<TakerBuySellVolume>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<u16>,
+    {
+        let query = HistoryQuery {
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            from_id: None,
+            limit: limit.into(),
+            symbol: symbol.into(),
+            interval: None,
+            period: Some(period.as_str().to_string()),
+        };
+        query.validate()?;
+        self.client
+            .get_d("/futures/data/takerlongshortRatio", Some(query))
+            .await
+    }
+
+    /// Basis of a pair's contract, only the last 30 days are available (Default 30; max 500)
+    pub async fn get_basis<S1, S2, S3, S4>(
+        &self,
+        pair: S1,
+        contract_type: ContractType,
+        period: StatsPeriod,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<Basis>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<u16>,
+    {
+        self.client
+            .get_d(
+                "/futures/data/basis",
+                Some(BasisQuery {
+                    pair: pair.into(),
+                    contract_type,
+                    period: period.as_str().to_string(),
+                    start_time: start_time.into(),
+                    end_time: end_time.into(),
+                    limit: limit.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Open interest history of a symbol between start_time and end_time (ms, inclusive),
+    /// paginated transparently
+    pub fn open_interest_history_stream<S>(
+        &self,
+        symbol: S,
+        period: StatsPeriod,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<OpenInterestHistory>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let symbol = symbol.into();
+        let cursor = HistoryCursor::Between {
+            start_time,
+            end_time,
+        };
+        paginate(
+            cursor,
+            stats_page_limits(period),
+            move |page: PageRequest| {
+                let market = market.clone();
+                let symbol = symbol.clone();
+                async move {
+                    market
+                        .get_open_interest_history(
+                            symbol,
+                            period,
+                            page.start_time,
+                            page.end_time,
+                            STATS_PAGE_SIZE as u16,
+                        )
+                        .await
+                }
+            },
+        )
+    }
+
+    /// Taker buy and sell volumes of a symbol between start_time and end_time (ms, inclusive),
+    /// paginated transparently
+    pub fn taker_buy_sell_volume_stream<S>(
+        &self,
+        symbol: S,
+        period: StatsPeriod,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<TakerBuySellVolume>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let symbol = symbol.into();
+        let cursor = HistoryCursor::Between {
+            start_time,
+            end_time,
+        };
+        paginate(
+            cursor,
+            stats_page_limits(period),
+            move |page: PageRequest| {
+                let market = market.clone();
+                let symbol = symbol.clone();
+                async move {
+                    market
+                        .get_taker_buy_sell_volume(
+                            symbol,
+                            period,
+                            page.start_time,
+                            page.end_time,
+                            STATS_PAGE_SIZE as u16,
+                        )
+                        .await
+                }
+            },
+        )
+    }
+
+    /// Basis of a pair's contract between start_time and end_time (ms, inclusive), paginated
+    /// transparently
+    pub fn basis_stream<S>(
+        &self,
+        pair: S,
+        contract_type: ContractType,
+        period: StatsPeriod,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<Basis>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let pair = pair.into();
+        let cursor = HistoryCursor::Between {
+            start_time,
+            end_time,
+        };
+        paginate(
+            cursor,
+            stats_page_limits(period),
+            move |page: PageRequest| {
+                let market = market.clone();
+                let pair = pair.clone();
+                async move {
+                    market
+                        .get_basis(
+                            pair,
+                            contract_type,
+                            period,
+                            page.start_time,
+                            page.end_time,
+                            STATS_PAGE_SIZE as u16,
+                        )
+                        .await
+                }
+            },
+        )
+    }
+
     /// Returns up to 'limit' klines for given symbol and interval ("1m", "5m", ...)
     /// https://github.com/binance-exchange/binance-official-api-docs/blob/master/rest-api.md#klinecandlestick-data
     pub async fn get_klines<S1, S2, S3, S4, S5>(
@@ -273,23 +640,7 @@ impl FuturesMarket {
         };
         let data: Vec<Vec<Value>> = self.client.get_d("/fapi/v1/klines", Some(query)).await?;
 
-        let klines = Klines::AllKlines(
-            data.iter()
-                .map(|row| Kline {
-                    open_time: to_i64(&row[0]),
-                    open: to_f64(&row[1]),
-                    high: to_f64(&row[2]),
-                    low: to_f64(&row[3]),
-                    close: to_f64(&row[4]),
-                    volume: to_f64(&row[5]),
-                    close_time: to_i64(&row[6]),
-                    quote_asset_volume: to_f64(&row[7]),
-                    number_of_trades: to_i64(&row[8]),
-                    taker_buy_base_asset_volume: to_f64(&row[9]),
-                    taker_buy_quote_asset_volume: to_f64(&row[10]),
-                })
-                .collect(),
-        );
+        let klines = Klines::AllKlines(data.iter().map(|row| to_kline(row)).collect());
         Ok(klines)
     }
 
@@ -436,6 +787,236 @@ impl FuturesMarket {
         Ok(klines)
     }
 
+    /// Returns up to 'limit' mark price klines for given symbol and interval ("1m", "5m", ...)
+    /// https://binance-docs.github.io/apidocs/futures/en/#mark-price-kline-candlestick-data
+    pub async fn get_mark_price_klines<S1, S2, S3, S4, S5>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<MarkPriceKlines>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let data = self
+            .get_mark_price_klines_v(symbol, interval, limit, start_time, end_time)
+            .await?;
+        Ok(MarkPriceKlines::AllMarkPriceKlines(
+            data.iter().map(|row| to_mark_price_kline(row)).collect(),
+        ))
+    }
+
+    /// Returns up to 'limit' index price klines for given pair and interval ("1m", "5m", ...)
+    /// https://binance-docs.github.io/apidocs/futures/en/#index-price-kline-candlestick-data
+    pub async fn get_index_price_klines<S1, S2, S3, S4, S5>(
+        &self,
+        pair: S1,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<IndexPriceKlines>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = PairKlinesQuery {
+            pair: pair.into(),
+            contract_type: None,
+            interval: interval.into(),
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+        };
+        let data: Vec<Vec<Value>> = self
+            .client
+            .get_d("/fapi/v1/indexPriceKlines", Some(query))
+            .await?;
+        Ok(IndexPriceKlines::AllIndexPriceKlines(
+            data.iter().map(|row| to_index_price_kline(row)).collect(),
+        ))
+    }
+
+    /// Returns up to 'limit' continuous contract klines for given pair, contract type and interval
+    /// https://binance-docs.github.io/apidocs/futures/en/#continuous-contract-kline-candlestick-data
+    pub async fn get_continuous_klines<S1, S2, S3, S4, S5>(
+        &self,
+        pair: S1,
+        contract_type: ContractType,
+        interval: S2,
+        limit: S3,
+        start_time: S4,
+        end_time: S5,
+    ) -> Result<ContinuousKlines>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+        S3: Into<u16>,
+        S4: Into<Option<u64>>,
+        S5: Into<Option<u64>>,
+    {
+        let query = PairKlinesQuery {
+            pair: pair.into(),
+            contract_type: Some(contract_type),
+            interval: interval.into(),
+            start_time: start_time.into(),
+            end_time: end_time.into(),
+            limit: limit.into(),
+        };
+        let data: Vec<Vec<Value>> = self
+            .client
+            .get_d("/fapi/v1/continuousKlines", Some(query))
+            .await?;
+        Ok(ContinuousKlines::AllContinuousKlines(
+            data.iter().map(|row| to_continuous_kline(row)).collect(),
+        ))
+    }
+
+    /// Download every kline opening between start_time and end_time (ms, inclusive),
+    /// splitting the range in as many requests as needed
+    pub async fn download_klines<S1, S2>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        start_time: u64,
+        end_time: u64,
+        options: DownloadOptions,
+    ) -> Result<KlineDownload<Kline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let symbol = symbol.into();
+        let interval = interval.into();
+        let interval_ms =
+            interval_millis(&interval).ok_or_else(|| Error::InvalidPeriod(interval.clone()))?;
+        download(start_time, end_time, interval_ms, options, |start, end| {
+            let klines = self.get_klines(
+                symbol.as_str(),
+                interval.as_str(),
+                options.chunk_size as u16,
+                start,
+                end,
+            );
+            async move {
+                let Klines::AllKlines(klines) = klines.await?;
+                Ok(klines)
+            }
+        })
+        .await
+    }
+
+    /// Download every mark price kline opening between start_time and end_time (ms, inclusive)
+    pub async fn download_mark_price_klines<S1, S2>(
+        &self,
+        symbol: S1,
+        interval: S2,
+        start_time: u64,
+        end_time: u64,
+        options: DownloadOptions,
+    ) -> Result<KlineDownload<MarkPriceKline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let symbol = symbol.into();
+        let interval = interval.into();
+        let interval_ms =
+            interval_millis(&interval).ok_or_else(|| Error::InvalidPeriod(interval.clone()))?;
+        download(start_time, end_time, interval_ms, options, |start, end| {
+            let klines = self.get_mark_price_klines(
+                symbol.as_str(),
+                interval.as_str(),
+                options.chunk_size as u16,
+                start,
+                end,
+            );
+            async move {
+                let MarkPriceKlines::AllMarkPriceKlines(klines) = klines.await?;
+                Ok(klines)
+            }
+        })
+        .await
+    }
+
+    /// Download every index price kline opening between start_time and end_time (ms, inclusive)
+    pub async fn download_index_price_klines<S1, S2>(
+        &self,
+        pair: S1,
+        interval: S2,
+        start_time: u64,
+        end_time: u64,
+        options: DownloadOptions,
+    ) -> Result<KlineDownload<IndexPriceKline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let pair = pair.into();
+        let interval = interval.into();
+        let interval_ms =
+            interval_millis(&interval).ok_or_else(|| Error::InvalidPeriod(interval.clone()))?;
+        download(start_time, end_time, interval_ms, options, |start, end| {
+            let klines = self.get_index_price_klines(
+                pair.as_str(),
+                interval.as_str(),
+                options.chunk_size as u16,
+                start,
+                end,
+            );
+            async move {
+                let IndexPriceKlines::AllIndexPriceKlines(klines) = klines.await?;
+                Ok(klines)
+            }
+        })
+        .await
+    }
+
+    /// Download every continuous contract kline opening between start_time and end_time
+    /// (ms, inclusive)
+    pub async fn download_continuous_klines<S1, S2>(
+        &self,
+        pair: S1,
+        contract_type: ContractType,
+        interval: S2,
+        start_time: u64,
+        end_time: u64,
+        options: DownloadOptions,
+    ) -> Result<KlineDownload<ContinuousKline>>
+    where
+        S1: Into<String>,
+        S2: Into<String>,
+    {
+        let pair = pair.into();
+        let interval = interval.into();
+        let interval_ms =
+            interval_millis(&interval).ok_or_else(|| Error::InvalidPeriod(interval.clone()))?;
+        download(start_time, end_time, interval_ms, options, |start, end| {
+            let klines = self.get_continuous_klines(
+                pair.as_str(),
+                contract_type,
+                interval.as_str(),
+                options.chunk_size as u16,
+                start,
+                end,
+            );
+            async move {
+                let ContinuousKlines::AllContinuousKlines(klines) = klines.await?;
+                Ok(klines)
+            }
+        })
+        .await
+    }
+
     /// https://binance-docs.github.io/apidocs/futures/en/#notional-and-leverage-brackets-user_data
     pub async fn get_notional_leverage_brackets<S>(&self, symbol: S) -> Result<SymbolBrackets>
     where
@@ -452,12 +1033,114 @@ impl FuturesMarket {
 
     /// https://binance-docs.github.io/apidocs/futures/en/#composite-index-symbol-information
     /// Only for composite symbols (ex: DEFIUSDT)
-    pub async fn get_index_info<S>(&self, symbol: Option<S>) -> Result<PriceStats>
+    pub async fn get_index_info<S>(&self, symbol: S) -> Result<IndexInfo>
     where
         S: Into<String>,
     {
-        let p = symbol.map(|s| PairQuery { symbol: s.into() });
-        self.client.get_d("/fapi/v1/indexInfo", p).await
+        self.client
+            .get_d(
+                "/fapi/v1/indexInfo",
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Composition of every composite index
+    pub async fn get_all_index_info(&self) -> Result<Vec<IndexInfo>> {
+        self.client.get_p("/fapi/v1/indexInfo", "").await
+    }
+
+    /// Mark price, index price and funding rate of a symbol
+    pub async fn get_premium_index<S>(&self, symbol: S) -> Result<PremiumIndex>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                "/fapi/v1/premiumIndex",
+                Some(PairQuery {
+                    symbol: symbol.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Mark price, index price and funding rate of all symbols
+    pub async fn get_all_premium_indexes(&self) -> Result<PremiumIndexs> {
+        self.client.get_p("/fapi/v1/premiumIndex", "").await
+    }
+
+    /// Time of the next funding of a symbol in ms
+    pub async fn get_next_funding_time<S>(&self, symbol: S) -> Result<u64>
+    where
+        S: Into<String>,
+    {
+        Ok(self.get_premium_index(symbol).await?.next_funding_time)
+    }
+
+    /// Funding rate history of a symbol, the oldest first (Default 100; max 1000)
+    pub async fn get_funding_rate<S1, S2, S3, S4>(
+        &self,
+        symbol: S1,
+        start_time: S2,
+        end_time: S3,
+        limit: S4,
+    ) -> Result<Vec<FundingRate>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u64>>,
+        S3: Into<Option<u64>>,
+        S4: Into<u16>,
+    {
+        self.client
+            .get_d(
+                "/fapi/v1/fundingRate",
+                Some(HistoryQuery {
+                    start_time: start_time.into(),
+                    end_time: end_time.into(),
+                    from_id: None,
+                    limit: limit.into(),
+                    symbol: symbol.into(),
+                    interval: None,
+                    period: None,
+                }),
+            )
+            .await
+    }
+
+    /// Every funding of a symbol between start_time and end_time (ms, inclusive), paginated
+    /// transparently
+    pub fn funding_rate_stream<S>(
+        &self,
+        symbol: S,
+        start_time: u64,
+        end_time: u64,
+    ) -> impl Stream<Item = Result<FundingRate>>
+    where
+        S: Into<String>,
+    {
+        let market = self.clone();
+        let symbol = symbol.into();
+        let cursor = HistoryCursor::Between {
+            start_time,
+            end_time,
+        };
+        paginate(cursor, FUNDING_PAGE_LIMITS, move |page: PageRequest| {
+            let market = market.clone();
+            let symbol = symbol.clone();
+            async move {
+                market
+                    .get_funding_rate(
+                        symbol,
+                        page.start_time,
+                        page.end_time,
+                        FUNDING_PAGE_LIMITS.page_size as u16,
+                    )
+                    .await
+            }
+        })
     }
 
     /// 24hr ticker price change statistics
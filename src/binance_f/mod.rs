@@ -8,7 +8,7 @@
 #![allow(clippy::needless_doctest_main)]
 pub use util::{bool_to_string, bool_to_string_some};
 
-mod client;
+pub(crate) mod client;
 pub mod errors;
 pub mod util;
 
@@ -20,5 +20,7 @@ pub mod api;
 pub mod config;
 pub mod general;
 pub mod market;
+pub mod positions;
 pub mod userstream;
 pub mod websockets;
+pub mod ws_api;
@@ -0,0 +1,425 @@
+/*!
+Live position and margin tracking for USD-M futures.
+
+A `PositionTracker` is seeded from `FuturesAccount::position_information` and
+`FuturesAccount::account_balance`, then kept current by feeding it the user data stream
+(`ACCOUNT_UPDATE`, `ORDER_TRADE_UPDATE`, `ACCOUNT_CONFIG_UPDATE`) and the mark price stream.
+
+Liquidation prices are estimates using Binance's published formula; they match the exchange
+only when the leverage brackets of each symbol have been provided with `set_brackets`.
+*/
+
+use std::str::FromStr;
+
+pub use super::account::MarginType;
+use super::account::{FuturesAccount, PositionSide};
+use super::errors::*;
+use super::rest_model::{AccountBalance, LeverageBracket, Position};
+use super::util::HashMap;
+use super::ws_model::{
+    AccountUpdateEvent, FuturesWebsocketEvent, MarkPriceEvent, OrderTradeUpdateEvent,
+};
+
+/// Maintenance margin rate used when no leverage bracket is known for a symbol
+pub static DEFAULT_MAINT_MARGIN_RATE: f64 = 0.004;
+
+#[derive(Debug, Clone)]
+pub struct TrackedPosition {
+    pub symbol: String,
+    pub position_side: PositionSide,
+    /// Signed position amount, negative when short
+    pub position_amount: f64,
+    pub entry_price: f64,
+    pub mark_price: f64,
+    pub leverage: u8,
+    pub margin_type: MarginType,
+    /// Margin allocated to the position, only relevant for isolated positions
+    pub isolated_wallet: f64,
+    /// Realized profit accumulated from trade updates since the tracker was seeded
+    pub realized_pnl: f64,
+    pub update_time: u64,
+}
+
+impl TrackedPosition {
+    pub fn is_open(&self) -> bool {
+        self.position_amount != 0.0
+    }
+
+    pub fn notional(&self) -> f64 {
+        self.position_amount.abs() * self.mark_price
+    }
+
+    pub fn unrealized_pnl(&self) -> f64 {
+        self.position_amount * (self.mark_price - self.entry_price)
+    }
+
+    /// 1 for long exposure, -1 for short exposure
+    fn direction(&self) -> f64 {
+        match self.position_side {
+            PositionSide::Long => 1.0,
+            PositionSide::Short => -1.0,
+            PositionSide::Both => self.position_amount.signum(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TrackedBalance {
+    pub asset: String,
+    pub wallet_balance: f64,
+    pub cross_wallet_balance: f64,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PositionTracker {
+    positions: HashMap<(String, PositionSide), TrackedPosition>,
+    balances: HashMap<String, TrackedBalance>,
+    brackets: HashMap<String, Vec<LeverageBracket>>,
+}
+
+impl PositionTracker {
+    /// Seed a tracker from REST snapshots of positions and balances
+    pub fn new(positions: Vec<Position>, balances: Vec<AccountBalance>) -> Result<Self> {
+        let mut tracker = Self::default();
+        for balance in balances {
+            tracker.balances.insert(
+                balance.asset.clone(),
+                TrackedBalance {
+                    asset: balance.asset,
+                    wallet_balance: balance.balance,
+                    cross_wallet_balance: balance.cross_wallet_balance,
+                    update_time: balance.update_time,
+                },
+            );
+        }
+        for position in positions {
+            let position_side = PositionSide::from_str(&position.position_side)?;
+            let leverage = position
+                .leverage
+                .parse()
+                .map_err(|_| Error::Msg(format!("invalid leverage {}", position.leverage)))?;
+            tracker.positions.insert(
+                (position.symbol.clone(), position_side),
+                TrackedPosition {
+                    symbol: position.symbol,
+                    position_side,
+                    position_amount: position.position_amount,
+                    entry_price: position.entry_price,
+                    mark_price: position.mark_price,
+                    leverage,
+                    margin_type: MarginType::parse(&position.margin_type),
+                    isolated_wallet: position.isolated_margin - position.unrealized_profit,
+                    realized_pnl: 0.0,
+                    update_time: 0,
+                },
+            );
+        }
+        Ok(tracker)
+    }
+
+    /// Seed a tracker with the positions of `symbols` and all balances of the account
+    pub async fn load<S>(account: &FuturesAccount, symbols: &[S]) -> Result<Self>
+    where
+        S: AsRef<str>,
+    {
+        let mut positions = vec![];
+        for symbol in symbols {
+            positions.extend(account.position_information(symbol.as_ref()).await?);
+        }
+        let balances = account.account_balance().await?;
+        Self::new(positions, balances)
+    }
+
+    /// Set the leverage brackets used to compute maintenance margin for `symbol`,
+    /// see `FuturesMarket::get_notional_leverage_brackets`
+    pub fn set_brackets<S>(&mut self, symbol: S, brackets: Vec<LeverageBracket>)
+    where
+        S: Into<String>,
+    {
+        self.brackets.insert(symbol.into(), brackets);
+    }
+
+    pub fn position(&self, symbol: &str, position_side: PositionSide) -> Option<&TrackedPosition> {
+        self.positions.get(&(symbol.to_string(), position_side))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = &TrackedPosition> {
+        self.positions.values()
+    }
+
+    pub fn open_positions(&self) -> impl Iterator<Item = &TrackedPosition> {
+        self.positions.values().filter(|p| p.is_open())
+    }
+
+    pub fn balance(&self, asset: &str) -> Option<&TrackedBalance> {
+        self.balances.get(asset)
+    }
+
+    /// Apply a websocket event, events unrelated to positions are ignored
+    pub fn on_event(&mut self, event: &FuturesWebsocketEvent) -> Result<()> {
+        match event {
+            FuturesWebsocketEvent::AccountUpdate(e) => self.on_account_update(e),
+            FuturesWebsocketEvent::OrderTradeUpdate(e) => self.on_order_trade_update(e),
+            FuturesWebsocketEvent::MarkPrice(e) => self.on_mark_price(e),
+            FuturesWebsocketEvent::AccountConfigUpdate(e) => {
+                if let Some(ac) = &e.ac {
+                    for p in self.positions.values_mut() {
+                        if p.symbol == ac.symbol {
+                            p.leverage = ac.leverage;
+                        }
+                    }
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    pub fn on_account_update(&mut self, event: &AccountUpdateEvent) -> Result<()> {
+        for b in &event.account_update.balances {
+            let balance = self
+                .balances
+                .entry(b.asset.clone())
+                .or_insert_with(|| TrackedBalance {
+                    asset: b.asset.clone(),
+                    ..TrackedBalance::default()
+                });
+            balance.wallet_balance = b.wallet_balance;
+            balance.cross_wallet_balance = b.cross_wallet_balance;
+            balance.update_time = event.event_time;
+        }
+        for p in &event.account_update.positions {
+            let position_side = PositionSide::from_str(&p.position_side)?;
+            let mark_price = self.last_mark_price(&p.symbol).unwrap_or(p.entry_price);
+            let leverage = self.last_leverage(&p.symbol).unwrap_or(1);
+            let position = self
+                .positions
+                .entry((p.symbol.clone(), position_side))
+                .or_insert_with(|| TrackedPosition {
+                    symbol: p.symbol.clone(),
+                    position_side,
+                    position_amount: 0.0,
+                    entry_price: 0.0,
+                    mark_price,
+                    leverage,
+                    margin_type: MarginType::Cross,
+                    isolated_wallet: 0.0,
+                    realized_pnl: 0.0,
+                    update_time: 0,
+                });
+            position.position_amount = p.position_amount;
+            position.entry_price = p.entry_price;
+            position.margin_type = MarginType::parse(&p.margin_type);
+            position.isolated_wallet = p.isolated_wallet;
+            position.update_time = event.event_time;
+        }
+        Ok(())
+    }
+
+    pub fn on_order_trade_update(&mut self, event: &OrderTradeUpdateEvent) -> Result<()> {
+        let update = &event.order_trade_update;
+        if update.execution_type != "TRADE" {
+            return Ok(());
+        }
+        let position_side = PositionSide::from_str(&update.position_side)?;
+        if let Some(position) = self
+            .positions
+            .get_mut(&(update.symbol.clone(), position_side))
+        {
+            position.realized_pnl += update.realized_profit;
+            position.update_time = event.event_time;
+        }
+        Ok(())
+    }
+
+    pub fn on_mark_price(&mut self, event: &MarkPriceEvent) -> Result<()> {
+        let mark_price: f64 = event.mark_price.parse()?;
+        for p in self.positions.values_mut() {
+            if p.symbol == event.symbol {
+                p.mark_price = mark_price;
+            }
+        }
+        Ok(())
+    }
+
+    /// Unrealized PnL of a single position at the last known mark price
+    pub fn unrealized_pnl(&self, symbol: &str, position_side: PositionSide) -> Option<f64> {
+        self.position(symbol, position_side)
+            .map(TrackedPosition::unrealized_pnl)
+    }
+
+    /// Unrealized PnL of all positions margined in `asset`
+    pub fn total_unrealized_pnl(&self, asset: &str) -> f64 {
+        self.positions
+            .values()
+            .filter(|p| self.margin_asset(&p.symbol) == Some(asset))
+            .map(TrackedPosition::unrealized_pnl)
+            .sum()
+    }
+
+    /// Maintenance margin required for a position at the last known mark price
+    pub fn maintenance_margin(&self, position: &TrackedPosition) -> f64 {
+        let (rate, cum) = self.maintenance_rate(&position.symbol, position.notional());
+        position.notional() * rate - cum
+    }
+
+    /// Margin ratio as displayed by Binance: maintenance margin over margin balance.
+    /// Isolated positions are computed on their own wallet, cross positions share the cross wallet
+    /// of their margin asset. The position is liquidated when the ratio reaches 1.
+    pub fn margin_ratio(&self, symbol: &str, position_side: PositionSide) -> Option<f64> {
+        let position = self.position(symbol, position_side)?;
+        let (maintenance, margin_balance) = match position.margin_type {
+            MarginType::Isolated => (
+                self.maintenance_margin(position),
+                position.isolated_wallet + position.unrealized_pnl(),
+            ),
+            MarginType::Cross => {
+                let asset = self.margin_asset(symbol)?;
+                let cross = self.cross_positions(asset);
+                (
+                    cross.iter().map(|p| self.maintenance_margin(p)).sum(),
+                    self.balances[asset].cross_wallet_balance
+                        + cross.iter().map(|p| p.unrealized_pnl()).sum::<f64>(),
+                )
+            }
+        };
+        if margin_balance <= 0.0 {
+            return Some(f64::INFINITY);
+        }
+        Some(maintenance / margin_balance)
+    }
+
+    /// Estimated liquidation price of a position, `None` if the position is flat or its margin
+    /// asset balance is unknown
+    pub fn liquidation_price(&self, symbol: &str, position_side: PositionSide) -> Option<f64> {
+        let position = self.position(symbol, position_side)?;
+        if !position.is_open() {
+            return None;
+        }
+        let wallet_balance = match position.margin_type {
+            MarginType::Isolated => position.isolated_wallet,
+            MarginType::Cross => {
+                let asset = self.margin_asset(symbol)?;
+                let others = self
+                    .cross_positions(asset)
+                    .into_iter()
+                    .filter(|p| !(p.symbol == position.symbol && p.position_side == position_side));
+                let (other_maintenance, other_pnl) = others.fold((0.0, 0.0), |(m, u), p| {
+                    (m + self.maintenance_margin(p), u + p.unrealized_pnl())
+                });
+                self.balances[asset].cross_wallet_balance - other_maintenance + other_pnl
+            }
+        };
+        let size = position.position_amount.abs();
+        let side = position.direction();
+        let (rate, cum) = self.maintenance_rate(&position.symbol, position.notional());
+        let price = (wallet_balance + cum - side * size * position.entry_price)
+            / (size * rate - side * size);
+        Some(price.max(0.0))
+    }
+
+    fn cross_positions(&self, asset: &str) -> Vec<&TrackedPosition> {
+        self.positions
+            .values()
+            .filter(|p| {
+                p.is_open()
+                    && p.margin_type == MarginType::Cross
+                    && self.margin_asset(&p.symbol) == Some(asset)
+            })
+            .collect()
+    }
+
+    /// The tracked balance asset the symbol is quoted in (ex: USDT for BTCUSDT)
+    fn margin_asset(&self, symbol: &str) -> Option<&str> {
+        self.balances
+            .keys()
+            .filter(|asset| symbol.ends_with(asset.as_str()))
+            .max_by_key(|asset| asset.len())
+            .map(|asset| asset.as_str())
+    }
+
+    fn maintenance_rate(&self, symbol: &str, notional: f64) -> (f64, f64) {
+        self.brackets
+            .get(symbol)
+            .and_then(|brackets| {
+                brackets.iter().find(|b| {
+                    notional >= b.notional_floor as f64 && notional < b.notional_cap as f64
+                })
+            })
+            .map(|b| (b.maint_margin_ratio, b.cum as f64))
+            .unwrap_or((DEFAULT_MAINT_MARGIN_RATE, 0.0))
+    }
+
+    fn last_mark_price(&self, symbol: &str) -> Option<f64> {
+        self.positions
+            .values()
+            .find(|p| p.symbol == symbol && p.mark_price > 0.0)
+            .map(|p| p.mark_price)
+    }
+
+    fn last_leverage(&self, symbol: &str) -> Option<u8> {
+        self.positions
+            .values()
+            .find(|p| p.symbol == symbol)
+            .map(|p| p.leverage)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn tracker(margin_type: &str, amount: f64) -> PositionTracker {
+        let position: Position = serde_json::from_value(serde_json::json!({
+            "entryPrice": "20000",
+            "marginType": margin_type,
+            "isAutoAddMargin": "false",
+            "isolatedMargin": "1000",
+            "leverage": "10",
+            "liquidationPrice": "0",
+            "markPrice": "20000",
+            "maxNotionalValue": "1000000",
+            "positionAmt": amount.to_string(),
+            "symbol": "BTCUSDT",
+            "unRealizedProfit": "0",
+            "positionSide": "BOTH"
+        }))
+        .unwrap();
+        let balance: AccountBalance = serde_json::from_value(serde_json::json!({
+            "accountAlias": "",
+            "asset": "USDT",
+            "balance": "1000",
+            "crossWalletBalance": "1000",
+            "crossUnPnl": "0",
+            "availableBalance": "1000",
+            "maxWithdrawAmount": "1000",
+            "marginAvailable": true,
+            "updateTime": 0
+        }))
+        .unwrap();
+        PositionTracker::new(vec![position], vec![balance]).unwrap()
+    }
+
+    #[test]
+    fn unrealized_pnl_follows_mark_price() {
+        let mut t = tracker("cross", -0.5);
+        let event: FuturesWebsocketEvent = serde_json::from_value(serde_json::json!({
+            "e": "markPriceUpdate", "E": 1, "s": "BTCUSDT", "p": "19000", "i": "19000",
+            "P": "19000", "r": "0.0001", "T": 2
+        }))
+        .unwrap();
+        t.on_event(&event).unwrap();
+        assert_eq!(t.unrealized_pnl("BTCUSDT", PositionSide::Both), Some(500.0));
+    }
+
+    #[test]
+    fn isolated_liquidation_price() {
+        let t = tracker("isolated", 1.0);
+        let price = t.liquidation_price("BTCUSDT", PositionSide::Both).unwrap();
+        // (1000 - 20000) / (0.004 - 1)
+        assert!((price - 19076.305).abs() < 1e-3, "{}", price);
+        let ratio = t.margin_ratio("BTCUSDT", PositionSide::Both).unwrap();
+        assert!((ratio - 0.08).abs() < 1e-9, "{}", ratio);
+    }
+}
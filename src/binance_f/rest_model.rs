@@ -373,11 +373,38 @@ pub struct Order {
     pub activation_price: f64,
     #[serde(with = "string_or_float", default = "default_price_rate")]
     pub price_rate: f64,
+    pub time: u64,
     pub update_time: u64,
     pub working_type: String,
+    /// Not sent for force orders
+    #[serde(default)]
     pub price_protect: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UserTrade {
+    pub buyer: bool,
+    #[serde(with = "string_or_float")]
+    pub commission: f64,
+    pub commission_asset: String,
+    pub id: u64,
+    pub maker: bool,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub realized_pnl: f64,
+    pub side: String,
+    pub position_side: String,
+    pub symbol: String,
+    pub time: u64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Transaction {
@@ -393,6 +420,8 @@ pub struct Transaction {
     pub avg_price: f64,
     #[serde(with = "string_or_float")]
     pub orig_qty: f64,
+    #[serde(default, with = "string_or_float")]
+    pub price: f64,
     pub reduce_only: bool,
     pub side: String,
     pub position_side: String,
@@ -413,7 +442,7 @@ pub struct Transaction {
     pub price_rate: Option<f64>,
     pub update_time: u64,
     pub working_type: String,
-    price_protect: bool,
+    pub price_protect: bool,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -498,6 +527,102 @@ pub struct AccountBalance {
     pub update_time: u64,
 }
 
+/// Balances and positions of the account, from the REST and the websocket APIs
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    pub fee_tier: u8,
+    pub can_trade: bool,
+    pub can_deposit: bool,
+    pub can_withdraw: bool,
+    pub update_time: u64,
+    #[serde(default)]
+    pub multi_assets_margin: bool,
+    #[serde(with = "string_or_float")]
+    pub total_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub total_margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub total_position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub total_cross_wallet_balance: f64,
+    #[serde(with = "string_or_float", rename = "totalCrossUnPnl")]
+    pub total_cross_unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: f64,
+    pub assets: Vec<AccountAsset>,
+    pub positions: Vec<AccountPosition>,
+}
+
+/// Margin asset of the account
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountAsset {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub margin_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(with = "string_or_float", rename = "crossUnPnl")]
+    pub cross_unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub available_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub max_withdraw_amount: f64,
+    #[serde(default)]
+    pub margin_available: bool,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountPosition {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maint_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub unrealized_profit: f64,
+    #[serde(with = "string_or_float")]
+    pub position_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub open_order_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub leverage: f64,
+    pub isolated: bool,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub max_notional: f64,
+    pub position_side: String,
+    #[serde(with = "string_or_float", rename = "positionAmt")]
+    pub position_amount: f64,
+    pub update_time: u64,
+}
+
 // Account models
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
@@ -520,6 +645,131 @@ pub struct MultiAssetsMarginResponse {
     pub multi_assets_margin: bool,
 }
 
+/// Direction of an isolated position margin change, sent as 1 or 2
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(try_from = "u8", into = "u8")]
+pub enum PositionMarginChange {
+    Add,
+    Reduce,
+}
+
+impl From<PositionMarginChange> for u8 {
+    fn from(change: PositionMarginChange) -> Self {
+        match change {
+            PositionMarginChange::Add => 1,
+            PositionMarginChange::Reduce => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for PositionMarginChange {
+    type Error = String;
+
+    fn try_from(change: u8) -> Result<Self, Self::Error> {
+        match change {
+            1 => Ok(Self::Add),
+            2 => Ok(Self::Reduce),
+            c => Err(format!("unknown position margin change {}", c)),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginResponse {
+    pub amount: f64,
+    pub code: i32,
+    pub msg: String,
+    #[serde(rename = "type")]
+    pub change: PositionMarginChange,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PositionMarginHistory {
+    pub symbol: String,
+    #[serde(rename = "type")]
+    pub change: PositionMarginChange,
+    /// Origin of the change, such as a trade or a manual adjustment
+    pub delta_type: Option<String>,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    pub asset: String,
+    pub time: u64,
+    pub position_side: String,
+}
+
+/// Auto-deleveraging queue position of each side, from 0 to 4, 4 being the first to be reduced
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub struct AdlQuantileValues {
+    pub long: Option<u8>,
+    pub short: Option<u8>,
+    /// Only sent in hedge mode, ignore the value
+    pub hedge: Option<u8>,
+    /// Only sent in one-way mode
+    pub both: Option<u8>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AdlQuantile {
+    pub symbol: String,
+    pub adl_quantile: AdlQuantileValues,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CommissionRate {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub maker_commission_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_commission_rate: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum IncomeType {
+    Transfer,
+    WelcomeBonus,
+    RealizedPnl,
+    FundingFee,
+    Commission,
+    InsuranceClear,
+    ReferralKickback,
+    CommissionRebate,
+    ApiRebate,
+    ContestReward,
+    CrossCollateralTransfer,
+    OptionsPremiumFee,
+    OptionsSettleProfit,
+    InternalTransfer,
+    AutoExchange,
+    #[serde(rename = "DELIVERED_SETTELMENT")]
+    DeliveredSettlement,
+    CoinSwapDeposit,
+    CoinSwapWithdraw,
+    PositionLimitIncreaseFee,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Income {
+    /// Empty for incomes not related to a symbol, such as transfers
+    pub symbol: String,
+    pub income_type: IncomeType,
+    #[serde(with = "string_or_float")]
+    pub income: f64,
+    pub asset: String,
+    pub info: String,
+    pub time: u64,
+    pub tran_id: u64,
+    pub trade_id: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct PairQuery {
@@ -613,6 +863,10 @@ pub enum OrderType {
     TakeProfit,
     TakeProfitLimit,
     LimitMaker,
+    Stop,
+    StopMarket,
+    TakeProfitMarket,
+    TrailingStopMarket,
     #[serde(other)]
     Other,
 }
@@ -978,6 +1232,18 @@ impl HistoryQuery {
     }
 }
 
+/// Kline query on a pair rather than a symbol (index price and continuous contract klines)
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PairKlinesQuery {
+    pub pair: String,
+    pub contract_type: Option<super::account::ContractType>,
+    pub interval: String,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: u16,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct FundingRate {
@@ -989,6 +1255,84 @@ pub struct FundingRate {
 
 pub static PERIODS: &[&str] = &["5m", "15m", "30m", "1h", "2h", "4h", "6h", "12h", "1d"];
 
+/// Period of the futures trading statistics, see `PERIODS`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatsPeriod {
+    Minutes5,
+    Minutes15,
+    Minutes30,
+    Hour1,
+    Hours2,
+    Hours4,
+    Hours6,
+    Hours12,
+    Day1,
+}
+
+impl StatsPeriod {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StatsPeriod::Minutes5 => "5m",
+            StatsPeriod::Minutes15 => "15m",
+            StatsPeriod::Minutes30 => "30m",
+            StatsPeriod::Hour1 => "1h",
+            StatsPeriod::Hours2 => "2h",
+            StatsPeriod::Hours4 => "4h",
+            StatsPeriod::Hours6 => "6h",
+            StatsPeriod::Hours12 => "12h",
+            StatsPeriod::Day1 => "1d",
+        }
+    }
+
+    /// Duration of the period in ms
+    pub fn millis(&self) -> u64 {
+        let minutes = match self {
+            StatsPeriod::Minutes5 => 5,
+            StatsPeriod::Minutes15 => 15,
+            StatsPeriod::Minutes30 => 30,
+            StatsPeriod::Hour1 => 60,
+            StatsPeriod::Hours2 => 2 * 60,
+            StatsPeriod::Hours4 => 4 * 60,
+            StatsPeriod::Hours6 => 6 * 60,
+            StatsPeriod::Hours12 => 12 * 60,
+            StatsPeriod::Day1 => 24 * 60,
+        };
+        minutes * 60 * 1000
+    }
+}
+
+impl std::str::FromStr for StatsPeriod {
+    type Err = super::errors::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let period = match s {
+            "5m" => StatsPeriod::Minutes5,
+            "15m" => StatsPeriod::Minutes15,
+            "30m" => StatsPeriod::Minutes30,
+            "1h" => StatsPeriod::Hour1,
+            "2h" => StatsPeriod::Hours2,
+            "4h" => StatsPeriod::Hours4,
+            "6h" => StatsPeriod::Hours6,
+            "12h" => StatsPeriod::Hours12,
+            "1d" => StatsPeriod::Day1,
+            _ => return Err(super::errors::Error::InvalidPeriod(s.to_string())),
+        };
+        Ok(period)
+    }
+}
+
+/// Basis query on a pair and contract type
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct BasisQuery {
+    pub pair: String,
+    pub contract_type: super::account::ContractType,
+    pub period: String,
+    pub start_time: Option<u64>,
+    pub end_time: Option<u64>,
+    pub limit: u16,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct OpenInterest {
@@ -1021,6 +1365,37 @@ pub struct LongShortRatio {
     pub timestamp: u64,
 }
 
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct TakerBuySellVolume {
+    #[serde(with = "string_or_float")]
+    pub buy_sell_ratio: f64,
+    #[serde(with = "string_or_float")]
+    pub buy_vol: f64,
+    #[serde(with = "string_or_float")]
+    pub sell_vol: f64,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Basis {
+    pub pair: String,
+    pub contract_type: super::account::ContractType,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+    #[serde(with = "string_or_float")]
+    pub futures_price: f64,
+    #[serde(with = "string_or_float")]
+    pub basis: f64,
+    #[serde(with = "string_or_float")]
+    pub basis_rate: f64,
+    /// Not applicable to perpetual contracts
+    #[serde(default, deserialize_with = "empty_string_or_float")]
+    pub annualized_basis_rate: Option<f64>,
+    pub timestamp: u64,
+}
+
 #[derive(Debug, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct LeverageBracket {
@@ -1108,35 +1483,18 @@ pub(crate) mod string_or_float_opt {
     }
 }
 
-pub(crate) mod string_or_bool {
-    use std::fmt;
-
-    use serde::{de, Deserialize, Deserializer, Serializer};
-
-    pub fn serialize<T, S>(value: &T, serializer: S) -> Result<S::Ok, S::Error>
-    where
-        T: fmt::Display,
-        S: Serializer,
-    {
-        serializer.collect_str(value)
-    }
-
-    pub fn deserialize<'de, D>(deserializer: D) -> Result<bool, D::Error>
-    where
-        D: Deserializer<'de>,
-    {
-        #[derive(Deserialize)]
-        #[serde(untagged)]
-        enum StringOrFloat {
-            String(String),
-            Bool(bool),
-        }
+pub(crate) use crate::binance::rest_model::string_or_bool;
 
-        match StringOrFloat::deserialize(deserializer)? {
-            StringOrFloat::String(s) => s.parse().map_err(de::Error::custom),
-            StringOrFloat::Bool(i) => Ok(i),
-        }
+/// Optional float sent as a string, empty when not applicable
+pub(crate) fn empty_string_or_float<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(None);
     }
+    s.parse().map(Some).map_err(serde::de::Error::custom)
 }
 
 fn default_stop_price() -> f64 {
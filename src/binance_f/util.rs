@@ -50,6 +50,15 @@ pub fn build_signed_request(
     }
 }
 
+/// Url encoded json of a list or object parameter, such as the orders of a batch
+pub fn to_json_param<S>(value: &S) -> Result<String>
+where
+    S: serde::Serialize,
+{
+    let json = serde_json::to_string(value)?;
+    Ok(url::form_urlencoded::byte_serialize(json.as_bytes()).collect())
+}
+
 pub fn build_signed_request_p<S>(payload: S, recv_window: u64) -> Result<String>
 where
     S: serde::Serialize,
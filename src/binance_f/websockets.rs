@@ -1,5 +1,7 @@
+use super::account::ContractType;
 use super::config::*;
 use super::errors::*;
+pub use crate::binance::websockets::DepthLevels;
 
 use awc::ws::Message;
 use log::debug;
@@ -76,10 +78,380 @@ fn combined_stream(streams: Vec<String>) -> String {
     streams.join("/")
 }
 
+/// Kline intervals accepted by futures kline streams, which have no `1s` klines
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum KlineInterval {
+    Minutes1,
+    Minutes3,
+    Minutes5,
+    Minutes15,
+    Minutes30,
+    Hours1,
+    Hours2,
+    Hours4,
+    Hours6,
+    Hours8,
+    Hours12,
+    Days1,
+    Days3,
+    Weeks1,
+    Months1,
+}
+
+impl KlineInterval {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Minutes1 => "1m",
+            Self::Minutes3 => "3m",
+            Self::Minutes5 => "5m",
+            Self::Minutes15 => "15m",
+            Self::Minutes30 => "30m",
+            Self::Hours1 => "1h",
+            Self::Hours2 => "2h",
+            Self::Hours4 => "4h",
+            Self::Hours6 => "6h",
+            Self::Hours8 => "8h",
+            Self::Hours12 => "12h",
+            Self::Days1 => "1d",
+            Self::Days3 => "3d",
+            Self::Weeks1 => "1w",
+            Self::Months1 => "1M",
+        }
+    }
+}
+
+impl std::fmt::Display for KlineInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl std::str::FromStr for KlineInterval {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Ok(match s {
+            "1m" => Self::Minutes1,
+            "3m" => Self::Minutes3,
+            "5m" => Self::Minutes5,
+            "15m" => Self::Minutes15,
+            "30m" => Self::Minutes30,
+            "1h" => Self::Hours1,
+            "2h" => Self::Hours2,
+            "4h" => Self::Hours4,
+            "6h" => Self::Hours6,
+            "8h" => Self::Hours8,
+            "12h" => Self::Hours12,
+            "1d" => Self::Days1,
+            "3d" => Self::Days3,
+            "1w" => Self::Weeks1,
+            "1M" => Self::Months1,
+            _ => return Err(Error::InvalidPeriod(s.to_string())),
+        })
+    }
+}
+
+/// Update speed of the book depth streams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DepthSpeed {
+    Ms100,
+    #[default]
+    Ms250,
+    Ms500,
+}
+
+/// Update speed of the mark price streams
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum MarkPriceSpeed {
+    Seconds1,
+    #[default]
+    Seconds3,
+}
+
+/// A market stream, rendered to and parsed from its canonical name (`btcusdt@markPrice@1s`...)
+///
+/// Symbols and pairs are lowercased when rendered and uppercased when parsed.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum StreamSpec {
+    AggTrade {
+        symbol: String,
+    },
+    MarkPrice {
+        symbol: String,
+        speed: MarkPriceSpeed,
+    },
+    AllMarkPrices {
+        speed: MarkPriceSpeed,
+    },
+    Kline {
+        symbol: String,
+        interval: KlineInterval,
+    },
+    ContinuousKline {
+        pair: String,
+        contract_type: ContractType,
+        interval: KlineInterval,
+    },
+    Ticker {
+        symbol: String,
+    },
+    AllTickers,
+    MiniTicker {
+        symbol: String,
+    },
+    AllMiniTickers,
+    BookTicker {
+        symbol: String,
+    },
+    AllBookTickers,
+    ForceOrder {
+        symbol: String,
+    },
+    AllForceOrders,
+    PartialDepth {
+        symbol: String,
+        levels: DepthLevels,
+        speed: DepthSpeed,
+    },
+    DiffDepth {
+        symbol: String,
+        speed: DepthSpeed,
+    },
+    CompositeIndex {
+        symbol: String,
+    },
+}
+
+/// The `FuturesWebsocketEvent` variant sent on a stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StreamEventKind {
+    AggrTrades,
+    MarkPrice,
+    Kline,
+    ContinuousKline,
+    DayTicker,
+    MiniTicker,
+    BookTicker,
+    Liquidation,
+    /// Partial depth streams send `depthUpdate` events as well
+    DepthOrderBook,
+    CompositeIndex,
+}
+
+impl StreamSpec {
+    pub fn event_kind(&self) -> StreamEventKind {
+        match self {
+            Self::AggTrade { .. } => StreamEventKind::AggrTrades,
+            Self::MarkPrice { .. } | Self::AllMarkPrices { .. } => StreamEventKind::MarkPrice,
+            Self::Kline { .. } => StreamEventKind::Kline,
+            Self::ContinuousKline { .. } => StreamEventKind::ContinuousKline,
+            Self::Ticker { .. } | Self::AllTickers => StreamEventKind::DayTicker,
+            Self::MiniTicker { .. } | Self::AllMiniTickers => StreamEventKind::MiniTicker,
+            Self::BookTicker { .. } | Self::AllBookTickers => StreamEventKind::BookTicker,
+            Self::ForceOrder { .. } | Self::AllForceOrders => StreamEventKind::Liquidation,
+            Self::PartialDepth { .. } | Self::DiffDepth { .. } => StreamEventKind::DepthOrderBook,
+            Self::CompositeIndex { .. } => StreamEventKind::CompositeIndex,
+        }
+    }
+
+    /// Whether each message is an array of events rather than a single event
+    pub fn is_array(&self) -> bool {
+        matches!(
+            self,
+            Self::AllMarkPrices { .. } | Self::AllTickers | Self::AllMiniTickers
+        )
+    }
+}
+
+fn contract_type_name(contract_type: &ContractType) -> &'static str {
+    match contract_type {
+        ContractType::Perpetual => "perpetual",
+        ContractType::CurrentMonth => "current_month",
+        ContractType::NextMonth => "next_month",
+        ContractType::CurrentQuarter => "current_quarter",
+        ContractType::NextQuarter => "next_quarter",
+    }
+}
+
+impl std::fmt::Display for StreamSpec {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let depth_speed = |speed: &DepthSpeed| match speed {
+            DepthSpeed::Ms100 => "@100ms",
+            DepthSpeed::Ms250 => "",
+            DepthSpeed::Ms500 => "@500ms",
+        };
+        let mark_price_speed = |speed: &MarkPriceSpeed| match speed {
+            MarkPriceSpeed::Seconds1 => "@1s",
+            MarkPriceSpeed::Seconds3 => "",
+        };
+        match self {
+            Self::AggTrade { symbol } => write!(f, "{}@aggTrade", symbol.to_lowercase()),
+            Self::MarkPrice { symbol, speed } => write!(
+                f,
+                "{}@markPrice{}",
+                symbol.to_lowercase(),
+                mark_price_speed(speed)
+            ),
+            Self::AllMarkPrices { speed } => {
+                write!(f, "!markPrice@arr{}", mark_price_speed(speed))
+            }
+            Self::Kline { symbol, interval } => {
+                write!(f, "{}@kline_{}", symbol.to_lowercase(), interval)
+            }
+            Self::ContinuousKline {
+                pair,
+                contract_type,
+                interval,
+            } => write!(
+                f,
+                "{}_{}@continuousKline_{}",
+                pair.to_lowercase(),
+                contract_type_name(contract_type),
+                interval
+            ),
+            Self::Ticker { symbol } => write!(f, "{}@ticker", symbol.to_lowercase()),
+            Self::AllTickers => f.write_str(all_ticker_stream()),
+            Self::MiniTicker { symbol } => write!(f, "{}@miniTicker", symbol.to_lowercase()),
+            Self::AllMiniTickers => f.write_str(all_mini_ticker_stream()),
+            Self::BookTicker { symbol } => write!(f, "{}@bookTicker", symbol.to_lowercase()),
+            Self::AllBookTickers => f.write_str(all_book_ticker_stream()),
+            Self::ForceOrder { symbol } => write!(f, "{}@forceOrder", symbol.to_lowercase()),
+            Self::AllForceOrders => f.write_str("!forceOrder@arr"),
+            Self::PartialDepth {
+                symbol,
+                levels,
+                speed,
+            } => write!(
+                f,
+                "{}@depth{}{}",
+                symbol.to_lowercase(),
+                levels.count(),
+                depth_speed(speed)
+            ),
+            Self::DiffDepth { symbol, speed } => {
+                write!(f, "{}@depth{}", symbol.to_lowercase(), depth_speed(speed))
+            }
+            Self::CompositeIndex { symbol } => {
+                write!(f, "{}@compositeIndex", symbol.to_lowercase())
+            }
+        }
+    }
+}
+
+impl std::str::FromStr for StreamSpec {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let invalid = || Error::Msg(format!("invalid stream name {}", s));
+        let kline_interval = |interval: &str| interval.parse::<KlineInterval>();
+        match s {
+            "!markPrice@arr" => {
+                return Ok(Self::AllMarkPrices {
+                    speed: MarkPriceSpeed::Seconds3,
+                })
+            }
+            "!markPrice@arr@1s" => {
+                return Ok(Self::AllMarkPrices {
+                    speed: MarkPriceSpeed::Seconds1,
+                })
+            }
+            "!ticker@arr" => return Ok(Self::AllTickers),
+            "!miniTicker@arr" => return Ok(Self::AllMiniTickers),
+            "!bookTicker" => return Ok(Self::AllBookTickers),
+            "!forceOrder@arr" => return Ok(Self::AllForceOrders),
+            _ => {}
+        }
+        let (name, stream) = s.split_once('@').ok_or_else(invalid)?;
+        if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_') {
+            return Err(invalid());
+        }
+        if let Some(interval) = stream.strip_prefix("continuousKline_") {
+            let (pair, contract_type) = name.split_once('_').ok_or_else(invalid)?;
+            let contract_type = match contract_type {
+                "perpetual" => ContractType::Perpetual,
+                "current_month" => ContractType::CurrentMonth,
+                "next_month" => ContractType::NextMonth,
+                "current_quarter" => ContractType::CurrentQuarter,
+                "next_quarter" => ContractType::NextQuarter,
+                _ => return Err(invalid()),
+            };
+            return Ok(Self::ContinuousKline {
+                pair: pair.to_uppercase(),
+                contract_type,
+                interval: kline_interval(interval)?,
+            });
+        }
+        if name.contains('_') && !name.chars().any(|c| c.is_ascii_digit()) {
+            // Only delivery contracts (btcusdt_230929) contain an underscore
+            return Err(invalid());
+        }
+        let symbol = name.to_uppercase();
+        let (stream, speed) = match stream.split_once('@') {
+            Some((stream, speed)) => (stream, Some(speed)),
+            None => (stream, None),
+        };
+        let depth_speed = |speed: Option<&str>| match speed {
+            None => Ok(DepthSpeed::Ms250),
+            Some("100ms") => Ok(DepthSpeed::Ms100),
+            Some("250ms") => Ok(DepthSpeed::Ms250),
+            Some("500ms") => Ok(DepthSpeed::Ms500),
+            Some(_) => Err(invalid()),
+        };
+        let spec = match stream {
+            "markPrice" => {
+                let speed = match speed {
+                    None => MarkPriceSpeed::Seconds3,
+                    Some("1s") => MarkPriceSpeed::Seconds1,
+                    Some(_) => return Err(invalid()),
+                };
+                return Ok(Self::MarkPrice { symbol, speed });
+            }
+            "depth" => {
+                return Ok(Self::DiffDepth {
+                    symbol,
+                    speed: depth_speed(speed)?,
+                })
+            }
+            "aggTrade" => Self::AggTrade { symbol },
+            "ticker" => Self::Ticker { symbol },
+            "miniTicker" => Self::MiniTicker { symbol },
+            "bookTicker" => Self::BookTicker { symbol },
+            "forceOrder" => Self::ForceOrder { symbol },
+            "compositeIndex" => Self::CompositeIndex { symbol },
+            _ => {
+                if let Some(interval) = stream.strip_prefix("kline_") {
+                    Self::Kline {
+                        symbol,
+                        interval: kline_interval(interval)?,
+                    }
+                } else if let Some(levels) = stream.strip_prefix("depth") {
+                    let levels = levels
+                        .parse()
+                        .ok()
+                        .and_then(DepthLevels::from_count)
+                        .ok_or_else(invalid)?;
+                    return Ok(Self::PartialDepth {
+                        symbol,
+                        levels,
+                        speed: depth_speed(speed)?,
+                    });
+                } else {
+                    return Err(invalid());
+                }
+            }
+        };
+        // Only depth and mark price streams take an update speed
+        if speed.is_some() {
+            return Err(invalid());
+        }
+        Ok(spec)
+    }
+}
+
 pub struct FuturesWebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug> {
     pub socket: Option<(ClientResponse, Framed<BoxedSocket, Codec>)>,
     sender: mpsc::Sender<WE>,
-    conf: Config,
+    ws_endpoint: String,
 }
 
 impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
@@ -94,19 +466,25 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
     /// # Examples
     /// see examples/binance_FuturesWebSockets.rs
     pub fn new_with_options(sender: mpsc::Sender<WE>, conf: Config) -> FuturesWebSockets<WE> {
+        Self::new_with_endpoint(sender, conf.futures_ws_endpoint)
+    }
+
+    /// New websocket holder connecting to the streams of another websocket endpoint, such as the
+    /// one of the options
+    pub fn new_with_endpoint<S: Into<String>>(
+        sender: mpsc::Sender<WE>,
+        ws_endpoint: S,
+    ) -> FuturesWebSockets<WE> {
         FuturesWebSockets {
             socket: None,
             sender,
-            conf,
+            ws_endpoint: ws_endpoint.into(),
         }
     }
 
     /// Connect to a websocket endpoint
     pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
-        let wss: String = format!(
-            "{}/{}/{}",
-            self.conf.futures_ws_endpoint, WS_ENDPOINT, endpoint
-        );
+        let wss: String = format!("{}/{}/{}", self.ws_endpoint, WS_ENDPOINT, endpoint);
 
         let client = Client::builder()
             .max_http_version(awc::http::Version::HTTP_11)
@@ -165,7 +543,7 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
                     }
                     None => {
                         return Err(Error::Msg(
-                            "Option::unwrap()` on a `None` value.".to_string(),
+                            "Websocket stream ended without a close frame".to_string(),
                         ))
                     }
                 }
@@ -175,3 +553,38 @@ impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> FuturesWebSockets<WE> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn renders_and_parses_stream_names() {
+        let specs = [
+            "btcusdt@markPrice@1s",
+            "!markPrice@arr",
+            "btcusdt_perpetual@continuousKline_4h",
+            "btcusdt@kline_1M",
+            "btcusdt_230929@depth10@500ms",
+            "btcusdt@depth",
+            "!forceOrder@arr",
+            "defiusdt@compositeIndex",
+        ];
+        for name in specs {
+            assert_eq!(name.parse::<StreamSpec>().unwrap().to_string(), name);
+        }
+        let spec = StreamSpec::ContinuousKline {
+            pair: "BTCUSDT".to_string(),
+            contract_type: ContractType::CurrentQuarter,
+            interval: KlineInterval::Minutes1,
+        };
+        assert_eq!(
+            spec.to_string(),
+            "btcusdt_current_quarter@continuousKline_1m"
+        );
+        assert_eq!(spec.event_kind(), StreamEventKind::ContinuousKline);
+        for name in ["btcusdt@kline_1s", "btcusdt@depth@1000ms", "btcusdt@trade"] {
+            assert!(name.parse::<StreamSpec>().is_err());
+        }
+    }
+}
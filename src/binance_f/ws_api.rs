@@ -0,0 +1,187 @@
+/*!
+Request/response client of the USD-M futures websocket API (`ws-fapi.binance.com`).
+
+Orders are placed, modified, canceled and queried over a single persistent connection instead
+of a REST request each, with the same models as `FuturesAccount`. Each request is answered by an
+awaitable response matched by id, and reports the usage of the rate limits it counts against.
+The client is the `WsApiClient` of the spot websocket API, on the futures endpoint.
+*/
+
+use std::collections::BTreeMap;
+
+use super::account::{OrderCancellation, OrderModification, OrderRequest, OrderStatusRequest};
+use super::config::Config;
+use super::errors::*;
+use super::rest_model::{
+    AccountBalance, AccountInformation, CanceledOrder, Order, Position, Transaction,
+};
+use super::ws_model::WsApiError;
+use crate::binance::ws_api::{to_params, WsApiClient, WsApiClientError};
+
+pub use crate::binance::ws_api::WsApiResult;
+
+static ORDER_PLACE: &str = "order.place";
+static ORDER_MODIFY: &str = "order.modify";
+static ORDER_CANCEL: &str = "order.cancel";
+static ORDER_STATUS: &str = "order.status";
+static ACCOUNT_POSITION: &str = "account.position";
+static ACCOUNT_BALANCE: &str = "account.balance";
+static ACCOUNT_STATUS: &str = "account.status";
+
+impl WsApiClientError for Error {
+    fn msg(msg: String) -> Self {
+        Error::Msg(msg)
+    }
+
+    fn api(error: WsApiError) -> Self {
+        Error::BinanceError {
+            response: error.into(),
+        }
+    }
+}
+
+/// Client of the USD-M futures websocket API
+pub type FuturesWsApi = WsApiClient<Error>;
+
+impl FuturesWsApi {
+    pub fn new_with_options(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        conf: Config,
+    ) -> FuturesWsApi {
+        WsApiClient::new(
+            api_key,
+            secret_key,
+            conf.futures_ws_api_endpoint,
+            conf.recv_window,
+        )
+    }
+
+    /// Place a validated order
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_f::{api::*, account::*, config::*, rest_model::*, ws_api::*};
+    /// let mut ws_api: FuturesWsApi = BinanceF::new_with_env(&Config::testnet());
+    /// let order = OrderRequest::stop_market("BTCUSDT", OrderSide::Sell, 0.01, 25000.0);
+    /// let transaction = actix_rt::System::new().block_on(async move {
+    ///     ws_api.connect().await?;
+    ///     ws_api.place_order(order).await
+    /// });
+    /// assert!(transaction.is_ok(), "{:?}", transaction);
+    /// ```
+    pub async fn place_order(&mut self, order: OrderRequest) -> Result<WsApiResult<Transaction>> {
+        order.validate()?;
+        let params = to_params(&order)?;
+        self.request(ORDER_PLACE, params, true).await
+    }
+
+    /// Change the price and quantity of an open limit order
+    pub async fn modify_order(
+        &mut self,
+        modification: OrderModification,
+    ) -> Result<WsApiResult<Transaction>> {
+        let params = to_params(&modification)?;
+        self.request(ORDER_MODIFY, params, true).await
+    }
+
+    /// Cancel an order
+    pub async fn cancel_order(
+        &mut self,
+        o: OrderCancellation,
+    ) -> Result<WsApiResult<CanceledOrder>> {
+        let params = to_params(&o)?;
+        self.request(ORDER_CANCEL, params, true).await
+    }
+
+    /// Status of an order
+    pub async fn order_status(&mut self, osr: OrderStatusRequest) -> Result<WsApiResult<Order>> {
+        let params = to_params(&osr)?;
+        self.request(ORDER_STATUS, params, true).await
+    }
+
+    /// Positions of a symbol
+    pub async fn position_information<S>(&mut self, symbol: S) -> Result<WsApiResult<Vec<Position>>>
+    where
+        S: Into<String>,
+    {
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), symbol.into());
+        self.request(ACCOUNT_POSITION, params, true).await
+    }
+
+    pub async fn account_balance(&mut self) -> Result<WsApiResult<Vec<AccountBalance>>> {
+        self.request(ACCOUNT_BALANCE, BTreeMap::new(), true).await
+    }
+
+    /// Balances and positions of the account
+    pub async fn account_status(&mut self) -> Result<WsApiResult<AccountInformation>> {
+        self.request(ACCOUNT_STATUS, BTreeMap::new(), true).await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::binance::ws_api::sign_params;
+    use crate::binance_f::rest_model::OrderSide;
+    use crate::binance_f::ws_model::WsApiResponse;
+
+    #[test]
+    fn serializes_order_parameters() {
+        let order = OrderRequest::stop_market("BTCUSDT", OrderSide::Sell, 0.01, 25000.0)
+            .reduce_only(true)
+            .price_protect(true);
+        let params = to_params(&order).unwrap();
+        assert_eq!(params.get("type").map(String::as_str), Some("STOP_MARKET"));
+        assert_eq!(params.get("stopPrice").map(String::as_str), Some("25000"));
+        assert_eq!(params.get("priceProtect").map(String::as_str), Some("TRUE"));
+        assert!(!params.contains_key("price"));
+
+        let order = OrderRequest::stop_market("1000PEPEUSDT", OrderSide::Buy, 1e6, 0.0000012);
+        let params = to_params(&order).unwrap();
+        assert_eq!(
+            params.get("stopPrice").map(String::as_str),
+            Some("0.0000012")
+        );
+
+        let signed = sign_params(params, "key", "secret", 1);
+        assert_eq!(signed.get("apiKey").map(String::as_str), Some("key"));
+        assert_eq!(signed.get("signature").map(String::len), Some(64));
+    }
+
+    #[test]
+    fn decodes_error_responses() {
+        let response = r#"{"id":"3","status":400,"error":{"code":-2022,
+            "msg":"ReduceOnly Order is rejected."},"rateLimits":[{"rateLimitType":"ORDERS",
+            "interval":"MINUTE","intervalNum":1,"limit":1200,"count":2}]}"#;
+        let response: WsApiResponse = serde_json::from_str(response).unwrap();
+        assert_eq!(response.error.map(|e| e.code), Some(-2022));
+        assert_eq!(response.rate_limits[0].count, 2);
+    }
+
+    #[test]
+    fn decodes_account_status() {
+        let response = r#"{"id":"5","status":200,"result":{"feeTier":0,"canTrade":true,
+            "canDeposit":true,"canWithdraw":true,"multiAssetsMargin":false,"updateTime":0,
+            "totalInitialMargin":"0.00000000","totalMaintMargin":"0.00000000",
+            "totalWalletBalance":"103.12345678","totalUnrealizedProfit":"0.00000000",
+            "totalMarginBalance":"103.12345678","totalPositionInitialMargin":"0.00000000",
+            "totalOpenOrderInitialMargin":"0.00000000","totalCrossWalletBalance":"103.12345678",
+            "totalCrossUnPnl":"0.00000000","availableBalance":"103.12345678",
+            "maxWithdrawAmount":"103.12345678","assets":[{"asset":"USDT",
+            "walletBalance":"103.12345678","unrealizedProfit":"0.00000000",
+            "marginBalance":"103.12345678","maintMargin":"0.00000000","initialMargin":"0.00000000",
+            "positionInitialMargin":"0.00000000","openOrderInitialMargin":"0.00000000",
+            "crossWalletBalance":"103.12345678","crossUnPnl":"0.00000000",
+            "availableBalance":"103.12345678","maxWithdrawAmount":"103.12345678",
+            "marginAvailable":true,"updateTime":1625474304765}],"positions":[{"symbol":"BTCUSDT",
+            "initialMargin":"0","maintMargin":"0","unrealizedProfit":"0.00000000",
+            "positionInitialMargin":"0","openOrderInitialMargin":"0","leverage":"100",
+            "isolated":true,"entryPrice":"0.00000","maxNotional":"250000","positionSide":"BOTH",
+            "positionAmt":"0","updateTime":0}]},"rateLimits":[]}"#;
+        let response: WsApiResponse = serde_json::from_str(response).unwrap();
+        let account: AccountInformation = serde_json::from_value(response.result.unwrap()).unwrap();
+        assert_eq!(account.assets[0].wallet_balance, 103.12345678);
+        assert_eq!(account.positions[0].leverage, 100.0);
+    }
+}
@@ -1,6 +1,10 @@
-use super::rest_model::{string_or_float, Asks, Bids};
+use super::errors::BinanceContentError;
+use super::rest_model::{string_or_float, string_or_float_opt, Asks, Bids};
 use serde::{Deserialize, Serialize};
 
+/// The websocket API messages are the same as on spot
+pub use crate::binance::ws_model::{RateLimitUsage, WsApiError, WsApiRequest, WsApiResponse};
+
 #[derive(Debug, Serialize, Deserialize)]
 #[serde(tag = "e")]
 pub enum FuturesWebsocketEvent {
@@ -10,6 +14,7 @@ pub enum FuturesWebsocketEvent {
     #[serde(alias = "markPriceUpdate")]
     MarkPrice(MarkPriceEvent),
 
+    /// Also carries BLVT NAV klines, with the NAV as prices and the real leverage as volume
     #[serde(alias = "kline")]
     Kline(KlineEvent),
 
@@ -31,16 +36,30 @@ pub enum FuturesWebsocketEvent {
     #[serde(alias = "depthUpdate")]
     DepthOrderBook(DepthOrderBookEvent),
 
-    // todo nav
-    // todo nav_kline
-    // todo composite_index
-    // todo margin_call
+    #[serde(alias = "nav")]
+    BlvtNav(BlvtNavEvent),
+
+    #[serde(alias = "compositeIndex")]
+    CompositeIndex(CompositeIndexEvent),
+
+    #[serde(alias = "MARGIN_CALL")]
+    MarginCall(MarginCallEvent),
     #[serde(alias = "ACCOUNT_UPDATE")]
     AccountUpdate(AccountUpdateEvent),
     #[serde(alias = "ORDER_TRADE_UPDATE")]
     OrderTradeUpdate(OrderTradeUpdateEvent),
     #[serde(alias = "ACCOUNT_CONFIG_UPDATE")]
     AccountConfigUpdate(AccountConfigUpdateEvent),
+    #[serde(alias = "STRATEGY_UPDATE")]
+    StrategyUpdate(StrategyUpdateEvent),
+    #[serde(alias = "GRID_UPDATE")]
+    GridUpdate(GridUpdateEvent),
+    /// The listen key of the user data stream expired, a new one must be requested
+    #[serde(alias = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpiredEvent),
+    /// Event type this version does not decode yet, such as `TRADE_LITE`
+    #[serde(other)]
+    Unknown,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
@@ -126,8 +145,11 @@ pub struct AccountConfigUpdateEvent {
     pub event_time: u64,
     #[serde(rename = "T")]
     pub transaction_time: u64,
-    pub ac: AccountConfigUpdate1,
-    #[serde(skip)]
+    /// Sent when the leverage of a symbol changes
+    #[serde(default)]
+    pub ac: Option<AccountConfigUpdate1>,
+    /// Sent when the multi-assets mode changes
+    #[serde(default)]
     pub ai: Option<AccountConfigUpdate2>,
 }
 
@@ -147,6 +169,165 @@ pub struct AccountConfigUpdate2 {
     pub multi_assets_mode: bool,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarginCallEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Only sent for crossed positions
+    #[serde(rename = "cw", default, with = "string_or_float_opt")]
+    pub cross_wallet_balance: Option<f64>,
+    #[serde(rename = "p")]
+    pub positions: Vec<MarginCallPosition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarginCallPosition {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ps")]
+    pub position_side: String,
+    #[serde(rename = "pa", with = "string_or_float")]
+    pub position_amount: f64,
+    #[serde(rename = "mt")]
+    pub margin_type: String,
+    /// Only meaningful for isolated positions
+    #[serde(rename = "iw", with = "string_or_float")]
+    pub isolated_wallet: f64,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(rename = "up", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+    #[serde(rename = "mm", with = "string_or_float")]
+    pub maintenance_margin_required: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct ListenKeyExpiredEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "listenKey", default)]
+    pub listen_key: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StrategyUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "su")]
+    pub strategy_update: StrategyUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct StrategyUpdate {
+    #[serde(rename = "si")]
+    pub strategy_id: u64,
+    #[serde(rename = "st")]
+    pub strategy_type: String,
+    #[serde(rename = "ss")]
+    pub strategy_status: String,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "ut")]
+    pub update_time: u64,
+    /// 8: not enough margin, 11: too many open orders, 12: not enough position, ...
+    #[serde(rename = "c")]
+    pub op_code: u32,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GridUpdateEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "gu")]
+    pub grid_update: GridUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct GridUpdate {
+    #[serde(rename = "si")]
+    pub strategy_id: u64,
+    #[serde(rename = "st")]
+    pub strategy_type: String,
+    #[serde(rename = "ss")]
+    pub strategy_status: String,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "r", with = "string_or_float")]
+    pub realized_pnl: f64,
+    #[serde(rename = "up", with = "string_or_float")]
+    pub unmatched_average_price: f64,
+    #[serde(rename = "uq", with = "string_or_float")]
+    pub unmatched_qty: f64,
+    #[serde(rename = "uf", with = "string_or_float")]
+    pub unmatched_fee: f64,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub matched_pnl: f64,
+    #[serde(rename = "ut")]
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CompositeIndexEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
+    #[serde(rename = "C")]
+    pub component: String,
+    #[serde(rename = "c")]
+    pub composition: Vec<IndexComposition>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexComposition {
+    #[serde(rename = "b")]
+    pub base_asset: String,
+    #[serde(rename = "q")]
+    pub quote_asset: String,
+    #[serde(rename = "w", with = "string_or_float")]
+    pub weight_in_quantity: f64,
+    #[serde(rename = "W", with = "string_or_float")]
+    pub weight_in_percentage: f64,
+    #[serde(rename = "i", with = "string_or_float")]
+    pub index_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlvtNavEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// BLVT name
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "m")]
+    pub tokens_issued: f64,
+    #[serde(rename = "b")]
+    pub baskets: Vec<BlvtBasket>,
+    #[serde(rename = "n")]
+    pub nav: f64,
+    #[serde(rename = "l")]
+    pub real_leverage: f64,
+    #[serde(rename = "t")]
+    pub target_leverage: f64,
+    #[serde(rename = "f")]
+    pub funding_ratio: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BlvtBasket {
+    /// Futures symbol
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "n")]
+    pub position: f64,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct Balance {
@@ -466,9 +647,9 @@ pub struct Kline {
     #[serde(rename = "i")]
     pub interval: String,
     #[serde(rename = "f")]
-    pub first_trade_id: i32,
+    pub first_trade_id: i64,
     #[serde(rename = "L")]
-    pub last_trade_id: i32,
+    pub last_trade_id: i64,
     #[serde(rename = "o")]
     pub open: String,
     #[serde(rename = "c")]
@@ -548,3 +729,39 @@ pub struct DepthOrderBookEvent {
     #[serde(rename = "a")]
     pub asks: Vec<Asks>,
 }
+
+impl From<WsApiError> for BinanceContentError {
+    fn from(e: WsApiError) -> Self {
+        BinanceContentError {
+            code: e.code,
+            msg: e.msg,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_user_stream_events() {
+        let margin_call = r#"{"e":"MARGIN_CALL","E":1587727187525,"cw":"3.16812045","p":[{"s":"ETHUSDT","ps":"LONG","pa":"1.327","mt":"CROSSED","iw":"0","mp":"187.17127","up":"-1.166074","mm":"1.614445"}]}"#;
+        let expired = r#"{"e":"listenKeyExpired","E":1576653824250}"#;
+        let config =
+            r#"{"e":"ACCOUNT_CONFIG_UPDATE","E":1611646737479,"T":1611646737476,"ai":{"j":true}}"#;
+        let grid = r#"{"e":"GRID_UPDATE","T":1669262908216,"E":1669262908218,"gu":{"si":176057039,"st":"GRID","ss":"WORKING","s":"BTCUSDT","r":"-0.00300716","up":"16720","uq":"-0.001","uf":"-0.00300716","mp":"0.0","ut":1669262908197}}"#;
+        for event in [margin_call, expired, config, grid] {
+            serde_json::from_str::<FuturesWebsocketEvent>(event).unwrap();
+        }
+    }
+
+    #[test]
+    fn decodes_unlisted_events_as_unknown() {
+        let trade_lite = r#"{"e":"TRADE_LITE","E":1721895408092,"T":1721895408214,"s":"BTCUSDT","q":"0.001","p":"0","m":false,"c":"z8hcUoOsqEdKMeKPSABslD","S":"BUY","L":"64089.20","l":"0.040","t":109100866,"i":8886774}"#;
+        let rejected = r#"{"e":"CONDITIONAL_ORDER_TRIGGER_REJECT","E":1685517224945,"T":1685517224955,"or":{"s":"ETHUSDT","i":155618472834,"r":"Due to the order could not be filled immediately, the FOK order has been rejected."}}"#;
+        for event in [trade_lite, rejected] {
+            let event: FuturesWebsocketEvent = serde_json::from_str(event).unwrap();
+            assert!(matches!(event, FuturesWebsocketEvent::Unknown));
+        }
+    }
+}
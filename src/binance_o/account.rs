@@ -0,0 +1,226 @@
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::Client;
+
+use serde::Serialize;
+
+static EAPI_ORDER: &str = "/eapi/v1/order";
+static EAPI_OPEN_ORDERS: &str = "/eapi/v1/openOrders";
+static EAPI_ALL_OPEN_ORDERS: &str = "/eapi/v1/allOpenOrders";
+static EAPI_POSITION: &str = "/eapi/v1/position";
+
+#[derive(Clone)]
+pub struct OptionsAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Order Request
+/// options only accept limit orders, the quantity being a number of contracts
+/// orders are validated before being sent by `OptionsAccount::place_order`
+#[derive(Default, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderRequest {
+    pub symbol: String,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    #[serde(rename = "quantity")]
+    pub qty: f64,
+    pub price: Option<f64>,
+    pub time_in_force: Option<TimeInForce>,
+    pub reduce_only: Option<bool>,
+    pub post_only: Option<bool>,
+    /// A unique id for the order, automatically generated if not sent.
+    pub client_order_id: Option<String>,
+    /// Subject the order to market maker protection
+    pub is_mmp: Option<bool>,
+}
+
+impl OrderRequest {
+    /// Limit order, good till canceled
+    pub fn limit<S: Into<String>>(symbol: S, side: OrderSide, qty: f64, price: f64) -> Self {
+        Self {
+            symbol: symbol.into(),
+            side,
+            order_type: OrderType::Limit,
+            qty,
+            price: Some(price),
+            time_in_force: Some(TimeInForce::GTC),
+            ..Self::default()
+        }
+    }
+
+    pub fn time_in_force(mut self, time_in_force: TimeInForce) -> Self {
+        self.time_in_force = Some(time_in_force);
+        self
+    }
+
+    /// Only reduce the position
+    pub fn reduce_only(mut self, reduce_only: bool) -> Self {
+        self.reduce_only = Some(reduce_only);
+        self
+    }
+
+    /// Reject the order instead of taking liquidity
+    pub fn post_only(mut self, post_only: bool) -> Self {
+        self.post_only = Some(post_only);
+        self
+    }
+
+    pub fn client_order_id<S: Into<String>>(mut self, id: S) -> Self {
+        self.client_order_id = Some(id.into());
+        self
+    }
+
+    pub fn mmp(mut self, is_mmp: bool) -> Self {
+        self.is_mmp = Some(is_mmp);
+        self
+    }
+
+    /// Check the parameters accepted by options
+    pub fn validate(&self) -> Result<()> {
+        let invalid = |msg: &str| {
+            Err(Error::InvalidOrderError {
+                msg: format!("{:?} order on {}: {}", self.order_type, self.symbol, msg),
+            })
+        };
+        if !matches!(self.order_type, OrderType::Limit) {
+            return invalid("options only accept limit orders");
+        }
+        if self.qty <= 0.0 || self.price.is_none_or(|p| p <= 0.0) {
+            return invalid("quantity and price must be positive");
+        }
+        match self.time_in_force {
+            Some(TimeInForce::GTC | TimeInForce::IOC | TimeInForce::FOK) => {}
+            _ => return invalid("time in force must be GTC, IOC or FOK"),
+        }
+        if self.post_only == Some(true) && self.time_in_force != Some(TimeInForce::GTC) {
+            return invalid("post only orders must be good till canceled");
+        }
+        Ok(())
+    }
+}
+
+/// Order Query
+/// identify an order of a symbol
+/// either order_id or client_order_id must be set
+#[derive(Default, Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderQuery {
+    pub symbol: String,
+    pub order_id: Option<u64>,
+    pub client_order_id: Option<String>,
+    /// Cannot be greater than 60000
+    pub recv_window: Option<u64>,
+}
+
+impl OptionsAccount {
+    /// Place a validated order
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_o::{api::*, account::*, rest_model::*, config::*};
+    /// let account: OptionsAccount = BinanceO::new_with_env(&Config::testnet());
+    /// let order = OrderRequest::limit("BTC-240329-70000-C", OrderSide::Buy, 0.1, 1500.0);
+    /// let order = tokio_test::block_on(account.place_order(order));
+    /// assert!(order.is_ok(), "{:?}", order);
+    /// ```
+    pub async fn place_order(&self, order: OrderRequest) -> Result<Order> {
+        order.validate()?;
+        self.client
+            .post_signed_p(EAPI_ORDER, order, self.recv_window)
+            .await
+    }
+
+    /// Cancel an order
+    pub async fn cancel_order(&self, query: OrderQuery) -> Result<Order> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .delete_signed_p(EAPI_ORDER, &query, recv_window)
+            .await
+    }
+
+    /// Status of an order, unknown once it is filled or canceled and no longer open
+    pub async fn order_status(&self, query: OrderQuery) -> Result<Order> {
+        let recv_window = query.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(EAPI_ORDER, Some(query), recv_window)
+            .await
+    }
+
+    /// Cancel every open order of a symbol
+    pub async fn cancel_all_open_orders<S>(&self, symbol: S) -> Result<CancelAllOpenOrdersResponse>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .delete_signed_p(
+                EAPI_ALL_OPEN_ORDERS,
+                SymbolQuery {
+                    symbol: Some(symbol.into()),
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Open orders of a symbol, of every symbol when not set
+    pub async fn get_open_orders<S>(&self, symbol: S) -> Result<Vec<Order>>
+    where
+        S: Into<Option<String>>,
+    {
+        self.client
+            .get_signed_p(
+                EAPI_OPEN_ORDERS,
+                Some(SymbolQuery {
+                    symbol: symbol.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Positions of a symbol, of every symbol when not set
+    pub async fn positions<S>(&self, symbol: S) -> Result<Vec<Position>>
+    where
+        S: Into<Option<String>>,
+    {
+        self.client
+            .get_signed_p(
+                EAPI_POSITION,
+                Some(SymbolQuery {
+                    symbol: symbol.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn validates_limit_orders() {
+        let order = OrderRequest::limit("BTC-240329-70000-C", OrderSide::Buy, 0.1, 1500.0);
+        assert!(order.clone().validate().is_ok());
+        assert!(order.clone().post_only(true).validate().is_ok());
+        assert!(order
+            .clone()
+            .time_in_force(TimeInForce::IOC)
+            .post_only(true)
+            .validate()
+            .is_err());
+        assert!(order
+            .clone()
+            .time_in_force(TimeInForce::GTX)
+            .validate()
+            .is_err());
+        let market = OrderRequest {
+            order_type: OrderType::Market,
+            ..order
+        };
+        assert!(market.validate().is_err());
+    }
+}
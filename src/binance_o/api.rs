@@ -0,0 +1,76 @@
+use super::account::*;
+use super::config::Config;
+use super::general::*;
+use super::market::*;
+use crate::binance_f::client::*;
+
+pub trait BinanceO: Sized {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    /// Create a binance API using environment variables for credentials
+    /// BINANCE_API_KEY=<your api key>
+    /// BINANCE_API_SECRET_KEY=<your secret key>
+    fn new_with_env(config: &Config) -> Self {
+        let api_key = std::env::var("BINANCE_API_KEY").ok();
+        let secret = std::env::var("BINANCE_API_SECRET_KEY").ok();
+        Self::new_with_config(api_key, secret, config)
+    }
+
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> Self;
+}
+
+impl BinanceO for OptionsGeneral {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> OptionsGeneral {
+        OptionsGeneral {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+        }
+    }
+}
+
+impl BinanceO for OptionsMarket {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> OptionsMarket {
+        OptionsMarket {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl BinanceO for OptionsAccount {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> OptionsAccount {
+        OptionsAccount {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.options_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
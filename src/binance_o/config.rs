@@ -0,0 +1,53 @@
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub options_rest_api_endpoint: String,
+    pub options_ws_endpoint: String,
+    pub recv_window: u64,
+}
+
+impl Config {
+    /// Configure binance with default production endpoints
+    /// # Examples
+    /// ```
+    /// use exrs::binance_o::config::Config;
+    /// let config = Config::default();
+    /// ```
+    pub fn default() -> Config {
+        Config {
+            options_rest_api_endpoint: "https://eapi.binance.com".into(),
+            options_ws_endpoint: "wss://nbstream.binance.com/eoptions".into(),
+
+            recv_window: 5000,
+        }
+    }
+
+    /// Configure binance with all testnet endpoints
+    /// # Examples
+    /// ```
+    /// use exrs::binance_o::config::Config;
+    /// let config = Config::testnet();
+    /// ```
+    pub fn testnet() -> Config {
+        Config::default()
+            .set_options_rest_api_endpoint("https://testnet.binanceops.com")
+            .set_options_ws_endpoint("wss://testnetws.binanceops.com")
+    }
+
+    pub fn set_options_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        options_rest_api_endpoint: T,
+    ) -> Self {
+        self.options_rest_api_endpoint = options_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_options_ws_endpoint<T: Into<String>>(mut self, options_ws_endpoint: T) -> Self {
+        self.options_ws_endpoint = options_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+}
@@ -0,0 +1,118 @@
+/*!
+## Implemented functionality
+- [x] `Order Book`
+- [x] `Recent Trades List`
+- [x] `Option Mark Price`
+- [x] `24hr Ticker Price Change Statistics`
+- [x] `Symbol Price Ticker` (index price of an underlying)
+*/
+
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::*;
+
+static EAPI_DEPTH: &str = "/eapi/v1/depth";
+static EAPI_TRADES: &str = "/eapi/v1/trades";
+static EAPI_MARK: &str = "/eapi/v1/mark";
+static EAPI_TICKER: &str = "/eapi/v1/ticker";
+static EAPI_INDEX: &str = "/eapi/v1/index";
+
+#[derive(Clone)]
+pub struct OptionsMarket {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+fn symbol_query(symbol: Option<String>) -> SymbolQuery {
+    SymbolQuery { symbol }
+}
+
+impl OptionsMarket {
+    /// Order book of an option (Default 100; valid limits are 10, 20, 50, 100, 500, 1000)
+    pub async fn get_depth<S1, S2>(&self, symbol: S1, limit: S2) -> Result<OrderBook>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        self.client
+            .get_d(
+                EAPI_DEPTH,
+                Some(LimitQuery {
+                    symbol: symbol.into(),
+                    limit: limit.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Recent trades of an option (Default 100; max 500)
+    pub async fn get_trades<S1, S2>(&self, symbol: S1, limit: S2) -> Result<Vec<Trade>>
+    where
+        S1: Into<String>,
+        S2: Into<Option<u16>>,
+    {
+        self.client
+            .get_d(
+                EAPI_TRADES,
+                Some(LimitQuery {
+                    symbol: symbol.into(),
+                    limit: limit.into(),
+                }),
+            )
+            .await
+    }
+
+    /// Mark price, implied volatility and greeks of an option
+    pub async fn get_mark_price<S>(&self, symbol: S) -> Result<MarkPrice>
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into();
+        let marks: Vec<MarkPrice> = self
+            .client
+            .get_d(EAPI_MARK, Some(symbol_query(Some(symbol.clone()))))
+            .await?;
+        marks.into_iter().next().ok_or(Error::UnknownSymbol(symbol))
+    }
+
+    /// Mark price, implied volatility and greeks of every option
+    pub async fn get_all_mark_prices(&self) -> Result<Vec<MarkPrice>> {
+        self.client.get_p(EAPI_MARK, "").await
+    }
+
+    /// 24hr ticker price change statistics of an option
+    pub async fn get_ticker<S>(&self, symbol: S) -> Result<Ticker>
+    where
+        S: Into<String>,
+    {
+        let symbol = symbol.into();
+        let tickers: Vec<Ticker> = self
+            .client
+            .get_d(EAPI_TICKER, Some(symbol_query(Some(symbol.clone()))))
+            .await?;
+        tickers
+            .into_iter()
+            .next()
+            .ok_or(Error::UnknownSymbol(symbol))
+    }
+
+    /// 24hr ticker price change statistics of every option
+    pub async fn get_all_tickers(&self) -> Result<Vec<Ticker>> {
+        self.client.get_p(EAPI_TICKER, "").await
+    }
+
+    /// Spot index price of an underlying such as `BTCUSDT`
+    pub async fn get_index_price<S>(&self, underlying: S) -> Result<IndexPrice>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_d(
+                EAPI_INDEX,
+                Some(UnderlyingQuery {
+                    underlying: underlying.into(),
+                }),
+            )
+            .await
+    }
+}
@@ -0,0 +1,27 @@
+/*!
+European options, on the eapi endpoints.
+
+Options are quoted and settled in USDT, a symbol such as `BTC-240329-70000-C` names the
+underlying, the expiry date, the strike price and the side. The client and the errors are
+shared with the USD-M futures of `binance_f`.
+*/
+#![deny(
+    unstable_features,
+    unused_must_use,
+    unused_mut,
+    unused_imports,
+    unused_import_braces
+)]
+#![allow(clippy::needless_doctest_main)]
+
+pub use crate::binance_f::errors;
+
+pub mod rest_model;
+pub mod ws_model;
+
+pub mod account;
+pub mod api;
+pub mod config;
+pub mod general;
+pub mod market;
+pub mod websockets;
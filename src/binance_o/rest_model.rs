@@ -0,0 +1,391 @@
+use serde::{Deserialize, Serialize};
+
+pub(crate) use crate::binance_f::rest_model::{string_or_float, string_or_float_opt};
+pub use crate::binance_f::rest_model::{
+    Asks, Bids, OrderSide, OrderType, RateLimit, ServerTime, TimeInForce,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Hash)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OptionSide {
+    Call,
+    Put,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeInformation {
+    pub timezone: String,
+    pub server_time: u64,
+    pub option_contracts: Vec<OptionContract>,
+    pub option_assets: Vec<OptionAsset>,
+    pub option_symbols: Vec<OptionSymbol>,
+    pub rate_limits: Vec<RateLimit>,
+}
+
+impl ExchangeInformation {
+    /// Expiry dates of the options on an underlying, in ascending order
+    pub fn expiries(&self, underlying: &str) -> Vec<u64> {
+        let mut expiries: Vec<u64> = self
+            .option_symbols
+            .iter()
+            .filter(|s| s.underlying == underlying)
+            .map(|s| s.expiry_date)
+            .collect();
+        expiries.sort_unstable();
+        expiries.dedup();
+        expiries
+    }
+
+    /// Strike prices of the options on an underlying expiring at `expiry_date`, in ascending
+    /// order
+    pub fn strikes(&self, underlying: &str, expiry_date: u64) -> Vec<f64> {
+        let mut strikes: Vec<f64> = self
+            .option_symbols
+            .iter()
+            .filter(|s| s.underlying == underlying && s.expiry_date == expiry_date)
+            .map(|s| s.strike_price)
+            .collect();
+        strikes.sort_by(f64::total_cmp);
+        strikes.dedup();
+        strikes
+    }
+
+    /// The option on an underlying for an expiry, a strike and a side
+    pub fn find_symbol(
+        &self,
+        underlying: &str,
+        expiry_date: u64,
+        strike_price: f64,
+        side: OptionSide,
+    ) -> Option<&OptionSymbol> {
+        self.option_symbols.iter().find(|s| {
+            s.underlying == underlying
+                && s.expiry_date == expiry_date
+                && s.strike_price == strike_price
+                && s.side == side
+        })
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionContract {
+    pub base_asset: String,
+    pub quote_asset: String,
+    pub underlying: String,
+    pub settle_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionAsset {
+    pub name: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct OptionSymbol {
+    pub symbol: String,
+    pub side: OptionSide,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    pub underlying: String,
+    pub expiry_date: u64,
+    /// Underlying quantity of a contract
+    pub unit: u64,
+    pub quote_asset: String,
+    #[serde(with = "string_or_float")]
+    pub maker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub taker_fee_rate: f64,
+    #[serde(with = "string_or_float")]
+    pub min_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub max_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub maintenance_margin: f64,
+    pub price_scale: u8,
+    pub quantity_scale: u8,
+    pub filters: Vec<OptionFilters>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(tag = "filterType")]
+pub enum OptionFilters {
+    #[serde(rename = "PRICE_FILTER")]
+    #[serde(rename_all = "camelCase")]
+    PriceFilter {
+        #[serde(with = "string_or_float")]
+        min_price: f64,
+        #[serde(with = "string_or_float")]
+        max_price: f64,
+        #[serde(with = "string_or_float")]
+        tick_size: f64,
+    },
+    #[serde(rename = "LOT_SIZE")]
+    #[serde(rename_all = "camelCase")]
+    LotSize {
+        #[serde(with = "string_or_float")]
+        min_qty: f64,
+        #[serde(with = "string_or_float")]
+        max_qty: f64,
+        #[serde(with = "string_or_float")]
+        step_size: f64,
+    },
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderBook {
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    pub bids: Vec<Bids>,
+    pub asks: Vec<Asks>,
+}
+
+/// Mark price, implied volatility and greeks of an option
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct MarkPrice {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(rename = "bidIV", with = "string_or_float")]
+    pub bid_iv: f64,
+    #[serde(rename = "askIV", with = "string_or_float")]
+    pub ask_iv: f64,
+    #[serde(rename = "markIV", with = "string_or_float")]
+    pub mark_iv: f64,
+    #[serde(with = "string_or_float")]
+    pub delta: f64,
+    #[serde(with = "string_or_float")]
+    pub theta: f64,
+    #[serde(with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(with = "string_or_float")]
+    pub vega: f64,
+    #[serde(with = "string_or_float")]
+    pub high_price_limit: f64,
+    #[serde(with = "string_or_float")]
+    pub low_price_limit: f64,
+    #[serde(default, with = "string_or_float_opt")]
+    pub risk_free_interest: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Ticker {
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price_change: f64,
+    #[serde(with = "string_or_float")]
+    pub price_change_percent: f64,
+    #[serde(with = "string_or_float")]
+    pub last_price: f64,
+    #[serde(with = "string_or_float")]
+    pub last_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub open: f64,
+    #[serde(with = "string_or_float")]
+    pub high: f64,
+    #[serde(with = "string_or_float")]
+    pub low: f64,
+    #[serde(with = "string_or_float")]
+    pub volume: f64,
+    #[serde(with = "string_or_float")]
+    pub amount: f64,
+    #[serde(with = "string_or_float")]
+    pub bid_price: f64,
+    #[serde(with = "string_or_float")]
+    pub ask_price: f64,
+    pub open_time: u64,
+    pub close_time: u64,
+    pub first_trade_id: u64,
+    pub trade_count: u64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    /// Estimated settlement price one hour before exercise, the index price otherwise
+    #[serde(with = "string_or_float")]
+    pub exercise_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexPrice {
+    pub time: u64,
+    #[serde(with = "string_or_float")]
+    pub index_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Trade {
+    pub id: u64,
+    pub trade_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub qty: f64,
+    #[serde(with = "string_or_float")]
+    pub quote_qty: f64,
+    /// 1 when the taker bought, -1 when the taker sold
+    pub side: i8,
+    pub time: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OrderStatus {
+    Accepted,
+    Rejected,
+    PartiallyFilled,
+    Filled,
+    Cancelled,
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub order_id: u64,
+    pub symbol: String,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub fee: f64,
+    pub side: OrderSide,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub reduce_only: bool,
+    pub post_only: bool,
+    pub create_time: u64,
+    pub update_time: u64,
+    pub status: OrderStatus,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    pub client_order_id: Option<String>,
+    pub price_scale: u8,
+    pub quantity_scale: u8,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+    /// Whether the order is subject to market maker protection
+    #[serde(default)]
+    pub mmp: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PositionSide {
+    Long,
+    Short,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct Position {
+    pub symbol: String,
+    pub side: PositionSide,
+    #[serde(with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(with = "string_or_float")]
+    pub quantity: f64,
+    #[serde(with = "string_or_float")]
+    pub reducible_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_value: f64,
+    /// Rate of return
+    #[serde(with = "string_or_float")]
+    pub ror: f64,
+    #[serde(rename = "unrealizedPNL", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+    #[serde(with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(with = "string_or_float")]
+    pub strike_price: f64,
+    #[serde(with = "string_or_float")]
+    pub position_cost: f64,
+    pub expiry_date: u64,
+    pub price_scale: u8,
+    pub quantity_scale: u8,
+    pub option_side: OptionSide,
+    pub quote_asset: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct CancelAllOpenOrdersResponse {
+    pub code: i16,
+    pub msg: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SymbolQuery {
+    pub symbol: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UnderlyingQuery {
+    pub underlying: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LimitQuery {
+    pub symbol: String,
+    pub limit: Option<u16>,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn option(expiry_date: u64, strike_price: &str, side: &str) -> String {
+        format!(
+            r#"{{"symbol":"BTC-{expiry_date}-{strike_price}-{side}","side":"{side}",
+            "strikePrice":"{strike_price}","underlying":"BTCUSDT","expiryDate":{expiry_date},
+            "unit":1,"quoteAsset":"USDT","makerFeeRate":"0.0002","takerFeeRate":"0.0002",
+            "minQty":"0.01","maxQty":"100","initialMargin":"0.15","maintenanceMargin":"0.075",
+            "minInitialMargin":"0.1","minMaintenanceMargin":"0.05","priceScale":0,
+            "quantityScale":2,"filters":[{{"filterType":"PRICE_FILTER","minPrice":"5",
+            "maxPrice":"10000","tickSize":"5"}},{{"filterType":"LOT_SIZE","minQty":"0.01",
+            "maxQty":"100","stepSize":"0.01"}}]}}"#,
+            side = if side == "C" { "CALL" } else { "PUT" },
+        )
+    }
+
+    #[test]
+    fn lists_expiries_and_strikes() {
+        let symbols = [
+            option(2000, "70000", "C"),
+            option(1000, "65000", "P"),
+            option(1000, "60000", "C"),
+            option(1000, "65000", "C"),
+        ]
+        .join(",");
+        let info: ExchangeInformation = serde_json::from_str(&format!(
+            r#"{{"timezone":"UTC","serverTime":0,"optionContracts":[],"optionAssets":[],
+            "optionSymbols":[{}],"rateLimits":[]}}"#,
+            symbols
+        ))
+        .unwrap();
+        assert_eq!(info.expiries("BTCUSDT"), vec![1000, 2000]);
+        assert_eq!(info.strikes("BTCUSDT", 1000), vec![60000.0, 65000.0]);
+        assert!(info.strikes("ETHUSDT", 1000).is_empty());
+        let put = info.find_symbol("BTCUSDT", 1000, 65000.0, OptionSide::Put);
+        assert_eq!(put.map(|s| s.side), Some(OptionSide::Put));
+    }
+}
@@ -0,0 +1,91 @@
+use super::config::*;
+use super::errors::*;
+use crate::binance_f::websockets::FuturesWebSockets;
+
+use actix_codec::Framed;
+use awc::{ws::Codec, BoxedSocket, ClientResponse};
+use local_channel::mpsc;
+use std::sync::atomic::AtomicBool;
+
+pub use crate::binance_f::websockets::WS_ENDPOINT;
+
+/// 24hr ticker, with the greeks, of an option such as `BTC-240329-70000-C`
+pub fn ticker_stream(symbol: &str) -> String {
+    format!("{}@ticker", symbol)
+}
+
+/// 24hr tickers of every option of an underlying asset (`BTC`) expiring on `expiry` (`240329`)
+pub fn expiry_ticker_stream(underlying_asset: &str, expiry: &str) -> String {
+    format!("{}@ticker@{}", underlying_asset, expiry)
+}
+
+/// Mark price of every option of an underlying asset (`BTC`), every second
+pub fn mark_price_stream(underlying_asset: &str) -> String {
+    format!("{}@markPrice", underlying_asset)
+}
+
+/// Spot index price of an underlying (`BTCUSDT`), every second
+pub fn index_stream(underlying: &str) -> String {
+    format!("{}@index", underlying)
+}
+
+/// Trades of an option, or of every option of an underlying asset (`BTC`)
+pub fn trade_stream(symbol_or_asset: &str) -> String {
+    format!("{}@trade", symbol_or_asset)
+}
+
+/// Connector to the options streams, the futures one on the options websocket endpoint
+pub struct OptionsWebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug> {
+    inner: FuturesWebSockets<WE>,
+}
+
+impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> OptionsWebSockets<WE> {
+    /// New websocket holder with default configuration
+    /// # Examples
+    /// ```rust,no_run
+    /// use std::sync::atomic::AtomicBool;
+    /// use exrs::binance_o::{websockets::*, ws_model::*};
+    ///
+    /// let (tx, mut rx) = local_channel::mpsc::channel::<OptionsWebsocketMessage>();
+    /// let running = AtomicBool::new(true);
+    /// let result = actix_rt::System::new().block_on(async move {
+    ///     let mut web_socket = OptionsWebSockets::new(tx);
+    ///     web_socket.connect(&mark_price_stream("BTC")).await?;
+    ///     actix_rt::spawn(async move {
+    ///         while let Some(message) = rx.recv().await {
+    ///             println!("{:?}", message.into_events());
+    ///         }
+    ///     });
+    ///     web_socket.event_loop(&running).await
+    /// });
+    /// assert!(result.is_ok(), "{:?}", result);
+    /// ```
+    pub fn new(sender: mpsc::Sender<WE>) -> OptionsWebSockets<WE> {
+        Self::new_with_options(sender, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options(sender: mpsc::Sender<WE>, conf: Config) -> OptionsWebSockets<WE> {
+        OptionsWebSockets {
+            inner: FuturesWebSockets::new_with_endpoint(sender, conf.options_ws_endpoint),
+        }
+    }
+
+    /// Connect to a websocket endpoint
+    pub async fn connect(&mut self, endpoint: &str) -> Result<()> {
+        self.inner.connect(endpoint).await
+    }
+
+    /// Disconnect from the endpoint
+    pub async fn disconnect(&mut self) -> Result<()> {
+        self.inner.disconnect().await
+    }
+
+    pub fn socket(&self) -> &Option<(ClientResponse, Framed<BoxedSocket, Codec>)> {
+        self.inner.socket()
+    }
+
+    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        self.inner.event_loop(running).await
+    }
+}
@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+
+use super::rest_model::string_or_float;
+
+/// Events of the options streams
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum OptionsWebsocketEvent {
+    #[serde(alias = "24hrTicker")]
+    Ticker(Box<TickerEvent>),
+
+    #[serde(alias = "markPrice")]
+    MarkPrice(MarkPriceEvent),
+
+    #[serde(alias = "index")]
+    Index(IndexEvent),
+
+    #[serde(alias = "trade")]
+    Trade(TradeEvent),
+}
+
+/// A message of the options streams, the mark price and expiry ticker streams send every option
+/// of an underlying at once
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum OptionsWebsocketMessage {
+    Event(OptionsWebsocketEvent),
+    Events(Vec<OptionsWebsocketEvent>),
+}
+
+impl OptionsWebsocketMessage {
+    pub fn into_events(self) -> Vec<OptionsWebsocketEvent> {
+        match self {
+            OptionsWebsocketMessage::Event(event) => vec![event],
+            OptionsWebsocketMessage::Events(events) => events,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TickerEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub open: f64,
+    #[serde(rename = "h", with = "string_or_float")]
+    pub high: f64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub low: f64,
+    #[serde(rename = "c", with = "string_or_float")]
+    pub close: f64,
+    /// In contracts
+    #[serde(rename = "V", with = "string_or_float")]
+    pub volume: f64,
+    /// In USDT
+    #[serde(rename = "A", with = "string_or_float")]
+    pub amount: f64,
+    #[serde(rename = "P", with = "string_or_float")]
+    pub price_change_percent: f64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price_change: f64,
+    #[serde(rename = "Q", with = "string_or_float")]
+    pub last_qty: f64,
+    #[serde(rename = "F")]
+    pub first_trade_id: u64,
+    #[serde(rename = "L")]
+    pub last_trade_id: u64,
+    #[serde(rename = "n")]
+    pub trade_count: u64,
+    #[serde(rename = "bo", with = "string_or_float")]
+    pub best_bid_price: f64,
+    #[serde(rename = "ao", with = "string_or_float")]
+    pub best_ask_price: f64,
+    #[serde(rename = "bq", with = "string_or_float")]
+    pub best_bid_qty: f64,
+    #[serde(rename = "aq", with = "string_or_float")]
+    pub best_ask_qty: f64,
+    #[serde(rename = "b", with = "string_or_float")]
+    pub bid_iv: f64,
+    #[serde(rename = "a", with = "string_or_float")]
+    pub ask_iv: f64,
+    #[serde(rename = "d", with = "string_or_float")]
+    pub delta: f64,
+    #[serde(rename = "t", with = "string_or_float")]
+    pub theta: f64,
+    #[serde(rename = "g", with = "string_or_float")]
+    pub gamma: f64,
+    #[serde(rename = "v", with = "string_or_float")]
+    pub vega: f64,
+    #[serde(rename = "vo", with = "string_or_float")]
+    pub mark_iv: f64,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: f64,
+    #[serde(rename = "hl", with = "string_or_float")]
+    pub high_price_limit: f64,
+    #[serde(rename = "ll", with = "string_or_float")]
+    pub low_price_limit: f64,
+    /// Estimated exercise price
+    #[serde(rename = "eep", with = "string_or_float")]
+    pub exercise_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct MarkPriceEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "mp", with = "string_or_float")]
+    pub mark_price: f64,
+}
+
+/// Spot index price of an underlying
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct IndexEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub underlying: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub index_price: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct TradeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "t")]
+    pub trade_id: String,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub price: f64,
+    #[serde(rename = "q", with = "string_or_float")]
+    pub qty: f64,
+    #[serde(rename = "b")]
+    pub buy_order_id: u64,
+    #[serde(rename = "a")]
+    pub sell_order_id: u64,
+    #[serde(rename = "T")]
+    pub trade_time: u64,
+    /// "1" when the taker bought, "-1" when the taker sold
+    #[serde(rename = "S")]
+    pub side: String,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_option_streams() {
+        let marks = r#"[{"e":"markPrice","E":1663684594227,"s":"ETH-220930-1500-C","mp":"30.3"},
+            {"e":"markPrice","E":1663684594228,"s":"ETH-220930-1500-P","mp":"12.1"}]"#;
+        let message: OptionsWebsocketMessage = serde_json::from_str(marks).unwrap();
+        assert_eq!(message.into_events().len(), 2);
+
+        let trade = r#"{"e":"trade","E":1591677941092,"s":"BTC-200630-9000-P","t":"20",
+            "p":"1000","q":"-2","b":4611781675939004417,"a":4611781675939004418,
+            "T":1591677567872,"S":"-1"}"#;
+        let message: OptionsWebsocketMessage = serde_json::from_str(trade).unwrap();
+        let OptionsWebsocketMessage::Event(OptionsWebsocketEvent::Trade(trade)) = message else {
+            panic!("unexpected message {:?}", message);
+        };
+        assert_eq!(trade.side, "-1");
+        assert_eq!(trade.qty, -2.0);
+    }
+}
@@ -0,0 +1,296 @@
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::Client;
+
+pub use crate::binance_f::account::{OrderCancellation, OrderRequest, OrderStatusRequest};
+
+static PAPI_BALANCE: &str = "/papi/v1/balance";
+static PAPI_ACCOUNT: &str = "/papi/v1/account";
+static PAPI_UM_ORDER: &str = "/papi/v1/um/order";
+static PAPI_UM_OPEN_ORDERS: &str = "/papi/v1/um/openOrders";
+static PAPI_UM_ALL_OPEN_ORDERS: &str = "/papi/v1/um/allOpenOrders";
+static PAPI_CM_ORDER: &str = "/papi/v1/cm/order";
+static PAPI_CM_OPEN_ORDERS: &str = "/papi/v1/cm/openOrders";
+static PAPI_CM_ALL_OPEN_ORDERS: &str = "/papi/v1/cm/allOpenOrders";
+static PAPI_MARGIN_ORDER: &str = "/papi/v1/margin/order";
+static PAPI_MARGIN_LOAN: &str = "/papi/v1/marginLoan";
+static PAPI_REPAY_LOAN: &str = "/papi/v1/repayLoan";
+
+#[derive(Clone)]
+pub struct PortfolioAccount {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+/// Validate a futures order for the papi order endpoints, which only take limit and market
+/// orders
+fn validate_futures_order(order: &OrderRequest) -> Result<()> {
+    order.validate()?;
+    match order.order_type {
+        OrderType::Limit | OrderType::Market => Ok(()),
+        _ => Err(Error::InvalidOrderError {
+            msg: format!(
+                "{:?} order on {}: portfolio margin only takes limit and market orders",
+                order.order_type, order.symbol
+            ),
+        }),
+    }
+}
+
+impl PortfolioAccount {
+    /// Unified balance of every asset
+    pub async fn balances(&self) -> Result<Vec<AccountBalance>> {
+        self.client
+            .get_signed_p(
+                PAPI_BALANCE,
+                Some(AssetQuery { asset: None }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Unified balance of an asset
+    pub async fn balance<S>(&self, asset: S) -> Result<AccountBalance>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                PAPI_BALANCE,
+                Some(AssetQuery {
+                    asset: Some(asset.into()),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Unified margin ratio, equity and status of the account
+    pub async fn account_information(&self) -> Result<AccountInformation> {
+        self.client
+            .get_signed_p(PAPI_ACCOUNT, None::<SymbolQuery>, self.recv_window)
+            .await
+    }
+
+    /// Place a validated limit or market order on USD-M futures
+    /// # Examples
+    /// ```rust,no_run
+    /// use exrs::binance_p::{api::*, account::*, config::*};
+    /// use exrs::binance_f::rest_model::{OrderSide, OrderType};
+    /// let account: PortfolioAccount = BinanceP::new_with_env(&Config::default());
+    /// let order = OrderRequest {
+    ///     symbol: "BTCUSDT".into(),
+    ///     side: OrderSide::Buy,
+    ///     order_type: OrderType::Market,
+    ///     qty: Some(0.001),
+    ///     ..OrderRequest::default()
+    /// };
+    /// let order = tokio_test::block_on(account.place_um_order(order));
+    /// assert!(order.is_ok(), "{:?}", order);
+    /// ```
+    pub async fn place_um_order(&self, order: OrderRequest) -> Result<UmOrder> {
+        validate_futures_order(&order)?;
+        self.client
+            .post_signed_p(PAPI_UM_ORDER, order, self.recv_window)
+            .await
+    }
+
+    /// Cancel an order on USD-M futures
+    pub async fn cancel_um_order(&self, o: OrderCancellation) -> Result<UmOrder> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .delete_signed_p(PAPI_UM_ORDER, &o, recv_window)
+            .await
+    }
+
+    /// Status of an order on USD-M futures
+    pub async fn um_order_status(&self, osr: OrderStatusRequest) -> Result<UmOrder> {
+        let recv_window = osr.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(PAPI_UM_ORDER, Some(osr), recv_window)
+            .await
+    }
+
+    /// Open orders of a USD-M symbol
+    pub async fn um_open_orders<S>(&self, symbol: S) -> Result<Vec<UmOrder>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                PAPI_UM_OPEN_ORDERS,
+                Some(SymbolQuery {
+                    symbol: symbol.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    pub async fn cancel_all_um_open_orders<S>(
+        &self,
+        symbol: S,
+    ) -> Result<CancelAllOpenOrdersResponse>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .delete_signed_p(
+                PAPI_UM_ALL_OPEN_ORDERS,
+                SymbolQuery {
+                    symbol: symbol.into(),
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Place a validated limit or market order on COIN-M futures, the quantity being a number
+    /// of contracts
+    pub async fn place_cm_order(&self, order: OrderRequest) -> Result<CmOrder> {
+        validate_futures_order(&order)?;
+        self.client
+            .post_signed_p(PAPI_CM_ORDER, order, self.recv_window)
+            .await
+    }
+
+    /// Cancel an order on COIN-M futures
+    pub async fn cancel_cm_order(&self, o: OrderCancellation) -> Result<CmOrder> {
+        let recv_window = o.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .delete_signed_p(PAPI_CM_ORDER, &o, recv_window)
+            .await
+    }
+
+    /// Status of an order on COIN-M futures
+    pub async fn cm_order_status(&self, osr: OrderStatusRequest) -> Result<CmOrder> {
+        let recv_window = osr.recv_window.unwrap_or(self.recv_window);
+        self.client
+            .get_signed_p(PAPI_CM_ORDER, Some(osr), recv_window)
+            .await
+    }
+
+    /// Open orders of a COIN-M symbol
+    pub async fn cm_open_orders<S>(&self, symbol: S) -> Result<Vec<CmOrder>>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .get_signed_p(
+                PAPI_CM_OPEN_ORDERS,
+                Some(SymbolQuery {
+                    symbol: symbol.into(),
+                }),
+                self.recv_window,
+            )
+            .await
+    }
+
+    pub async fn cancel_all_cm_open_orders<S>(
+        &self,
+        symbol: S,
+    ) -> Result<CancelAllOpenOrdersResponse>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .delete_signed_p(
+                PAPI_CM_ALL_OPEN_ORDERS,
+                SymbolQuery {
+                    symbol: symbol.into(),
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Place a cross margin order, isolated margin is not available to portfolio margin
+    pub async fn place_margin_order(&self, order: MarginOrder) -> Result<MarginOrderResult> {
+        if order.is_isolated.is_some() {
+            return Err(Error::InvalidOrderError {
+                msg: format!(
+                    "margin order on {}: portfolio margin only trades cross margin",
+                    order.symbol
+                ),
+            });
+        }
+        self.client
+            .post_signed_p(PAPI_MARGIN_ORDER, order, self.recv_window)
+            .await
+    }
+
+    /// Cancel a cross margin order
+    pub async fn cancel_margin_order<S>(
+        &self,
+        symbol: S,
+        order_id: u64,
+    ) -> Result<MarginOrderCancellationResult>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .delete_signed_p(
+                PAPI_MARGIN_ORDER,
+                MarginCancellationQuery {
+                    symbol: symbol.into(),
+                    order_id,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Borrow an asset on cross margin
+    pub async fn margin_loan<S>(&self, asset: S, amount: f64) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                PAPI_MARGIN_LOAN,
+                LoanQuery {
+                    asset: asset.into(),
+                    amount,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+
+    /// Repay a cross margin loan
+    pub async fn repay_loan<S>(&self, asset: S, amount: f64) -> Result<TransactionId>
+    where
+        S: Into<String>,
+    {
+        self.client
+            .post_signed_p(
+                PAPI_REPAY_LOAN,
+                LoanQuery {
+                    asset: asset.into(),
+                    amount,
+                },
+                self.recv_window,
+            )
+            .await
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn rejects_conditional_futures_orders() {
+        let market = OrderRequest {
+            symbol: "BTCUSDT".into(),
+            side: OrderSide::Buy,
+            order_type: OrderType::Market,
+            qty: Some(0.001),
+            ..OrderRequest::default()
+        };
+        assert!(validate_futures_order(&market).is_ok());
+        let stop = OrderRequest::stop_market("BTCUSDT", OrderSide::Sell, 0.001, 25000.0);
+        assert!(stop.validate().is_ok());
+        assert!(validate_futures_order(&stop).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+use super::account::*;
+use super::config::Config;
+use super::general::*;
+use super::userstream::*;
+use crate::binance_f::client::*;
+
+pub trait BinanceP: Sized {
+    fn new(api_key: Option<String>, secret_key: Option<String>) -> Self {
+        Self::new_with_config(api_key, secret_key, &Config::default())
+    }
+
+    /// Create a binance API using environment variables for credentials
+    /// BINANCE_API_KEY=<your api key>
+    /// BINANCE_API_SECRET_KEY=<your secret key>
+    fn new_with_env(config: &Config) -> Self {
+        let api_key = std::env::var("BINANCE_API_KEY").ok();
+        let secret = std::env::var("BINANCE_API_SECRET_KEY").ok();
+        Self::new_with_config(api_key, secret, config)
+    }
+
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> Self;
+}
+
+impl BinanceP for PortfolioGeneral {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> PortfolioGeneral {
+        PortfolioGeneral {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.portfolio_rest_api_endpoint.clone(),
+            ),
+        }
+    }
+}
+
+impl BinanceP for PortfolioAccount {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> PortfolioAccount {
+        PortfolioAccount {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.portfolio_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
+
+impl BinanceP for PortfolioUserStream {
+    fn new_with_config(
+        api_key: Option<String>,
+        secret_key: Option<String>,
+        config: &Config,
+    ) -> PortfolioUserStream {
+        PortfolioUserStream {
+            client: Client::new(
+                api_key,
+                secret_key,
+                config.portfolio_rest_api_endpoint.clone(),
+            ),
+            recv_window: config.recv_window,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub portfolio_rest_api_endpoint: String,
+    pub portfolio_ws_endpoint: String,
+    pub recv_window: u64,
+}
+
+impl Config {
+    /// Configure binance with default production endpoints, portfolio margin has no testnet
+    /// # Examples
+    /// ```
+    /// use exrs::binance_p::config::Config;
+    /// let config = Config::default();
+    /// ```
+    pub fn default() -> Config {
+        Config {
+            portfolio_rest_api_endpoint: "https://papi.binance.com".into(),
+            portfolio_ws_endpoint: "wss://fstream.binance.com/pm".into(),
+
+            recv_window: 5000,
+        }
+    }
+
+    pub fn set_portfolio_rest_api_endpoint<T: Into<String>>(
+        mut self,
+        portfolio_rest_api_endpoint: T,
+    ) -> Self {
+        self.portfolio_rest_api_endpoint = portfolio_rest_api_endpoint.into();
+        self
+    }
+
+    pub fn set_portfolio_ws_endpoint<T: Into<String>>(mut self, portfolio_ws_endpoint: T) -> Self {
+        self.portfolio_ws_endpoint = portfolio_ws_endpoint.into();
+        self
+    }
+
+    pub fn set_recv_window(mut self, recv_window: u64) -> Self {
+        self.recv_window = recv_window;
+        self
+    }
+}
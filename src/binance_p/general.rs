@@ -0,0 +1,15 @@
+use super::errors::*;
+use crate::binance_f::client::*;
+
+#[derive(Clone)]
+pub struct PortfolioGeneral {
+    pub client: Client,
+}
+
+impl PortfolioGeneral {
+    // Test connectivity
+    pub async fn ping(&self) -> Result<String> {
+        self.client.get("/papi/v1/ping", "").await?;
+        Ok("pong".into())
+    }
+}
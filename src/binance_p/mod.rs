@@ -0,0 +1,28 @@
+/*!
+Portfolio margin accounts, on the papi endpoints.
+
+An account in portfolio margin mode trades USD-M futures (UM), COIN-M futures (CM) and cross
+margin from a single unified balance, the classic fapi, dapi and sapi margin endpoints reject
+it. The client, the errors and the futures order requests are shared with `binance_f`, cross
+margin orders are the `MarginOrder` of `binance`.
+*/
+#![deny(
+    unstable_features,
+    unused_must_use,
+    unused_mut,
+    unused_imports,
+    unused_import_braces
+)]
+#![allow(clippy::needless_doctest_main)]
+
+pub use crate::binance_f::errors;
+
+pub mod rest_model;
+pub mod ws_model;
+
+pub mod account;
+pub mod api;
+pub mod config;
+pub mod general;
+pub mod userstream;
+pub mod websockets;
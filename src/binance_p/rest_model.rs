@@ -0,0 +1,193 @@
+use serde::{Deserialize, Serialize};
+
+pub use crate::binance::rest_model::{
+    MarginOrder, MarginOrderCancellationResult, MarginOrderResult, TransactionId,
+};
+pub use crate::binance_f::account::PositionSide;
+pub(crate) use crate::binance_f::rest_model::{
+    empty_string_or_float, string_or_float, string_or_float_opt,
+};
+pub use crate::binance_f::rest_model::{
+    CancelAllOpenOrdersResponse, OrderSide, OrderType, Success, TimeInForce, UserDataStream,
+};
+
+/// Balance of an asset across cross margin, UM and CM
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountBalance {
+    pub asset: String,
+    #[serde(with = "string_or_float")]
+    pub total_wallet_balance: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_asset: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_borrowed: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_free: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_interest: f64,
+    #[serde(with = "string_or_float")]
+    pub cross_margin_locked: f64,
+    #[serde(with = "string_or_float")]
+    pub um_wallet_balance: f64,
+    #[serde(
+        rename = "umUnrealizedPNL",
+        default,
+        deserialize_with = "empty_string_or_float"
+    )]
+    pub um_unrealized_pnl: Option<f64>,
+    #[serde(with = "string_or_float")]
+    pub cm_wallet_balance: f64,
+    #[serde(
+        rename = "cmUnrealizedPNL",
+        default,
+        deserialize_with = "empty_string_or_float"
+    )]
+    pub cm_unrealized_pnl: Option<f64>,
+    pub update_time: u64,
+    #[serde(default, deserialize_with = "empty_string_or_float")]
+    pub negative_balance: Option<f64>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum AccountStatus {
+    Normal,
+    MarginCall,
+    SupplyMargin,
+    ReduceOnly,
+    ActiveLiquidation,
+    ForceLiquidation,
+    Bankrupted,
+    #[serde(other)]
+    Other,
+}
+
+/// Unified margin of the account, in USD
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountInformation {
+    /// Unified maintenance margin ratio
+    #[serde(rename = "uniMMR", with = "string_or_float")]
+    pub uni_mmr: f64,
+    #[serde(with = "string_or_float")]
+    pub account_equity: f64,
+    /// Equity without the collateral rate
+    #[serde(with = "string_or_float")]
+    pub actual_equity: f64,
+    #[serde(with = "string_or_float")]
+    pub account_initial_margin: f64,
+    #[serde(with = "string_or_float")]
+    pub account_maint_margin: f64,
+    pub account_status: AccountStatus,
+    #[serde(with = "string_or_float")]
+    pub virtual_max_withdraw_amount: f64,
+    #[serde(default, deserialize_with = "empty_string_or_float")]
+    pub total_available_balance: Option<f64>,
+    #[serde(default, deserialize_with = "empty_string_or_float")]
+    pub total_margin_open_loss: Option<f64>,
+    pub update_time: u64,
+}
+
+/// An order on USD-M futures
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct UmOrder {
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub cum_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cum_quote: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: String,
+    pub symbol: String,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub update_time: u64,
+}
+
+/// An order on COIN-M futures, quantities are in contracts
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct CmOrder {
+    pub client_order_id: String,
+    #[serde(with = "string_or_float")]
+    pub cum_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub cum_base: f64,
+    #[serde(with = "string_or_float")]
+    pub executed_qty: f64,
+    pub order_id: u64,
+    #[serde(with = "string_or_float")]
+    pub avg_price: f64,
+    #[serde(with = "string_or_float")]
+    pub orig_qty: f64,
+    #[serde(with = "string_or_float")]
+    pub price: f64,
+    pub reduce_only: bool,
+    pub side: OrderSide,
+    pub position_side: PositionSide,
+    pub status: String,
+    pub symbol: String,
+    pub pair: String,
+    pub time_in_force: TimeInForce,
+    #[serde(rename = "type")]
+    pub order_type: OrderType,
+    pub update_time: u64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SymbolQuery {
+    pub symbol: String,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct AssetQuery {
+    pub asset: Option<String>,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct LoanQuery {
+    pub asset: String,
+    pub amount: f64,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MarginCancellationQuery {
+    pub symbol: String,
+    pub order_id: u64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_unified_balance() {
+        let balance = r#"{"asset":"USDT","totalWalletBalance":"122607.35137903",
+            "crossMarginAsset":"92.27530794","crossMarginBorrowed":"10.00000000",
+            "crossMarginFree":"100.00000000","crossMarginInterest":"0.72469206",
+            "crossMarginLocked":"3.00000000","umWalletBalance":"0.00000000",
+            "umUnrealizedPNL":"23.72469206","cmWalletBalance":"23.72469206",
+            "cmUnrealizedPNL":"","updateTime":1617939110373,"negativeBalance":"0"}"#;
+        let balance: AccountBalance = serde_json::from_str(balance).unwrap();
+        assert_eq!(balance.um_unrealized_pnl, Some(23.72469206));
+        assert_eq!(balance.cm_unrealized_pnl, None);
+    }
+}
@@ -0,0 +1,49 @@
+use serde_json::from_str;
+
+use super::errors::*;
+use super::rest_model::*;
+use crate::binance_f::client::*;
+
+static PORTFOLIO_USER_DATA_STREAM: &str = "/papi/v1/listenKey";
+
+/// The single user data stream of a portfolio margin account, carrying the UM, CM and cross
+/// margin events
+#[derive(Clone)]
+pub struct PortfolioUserStream {
+    pub client: Client,
+    pub recv_window: u64,
+}
+
+impl PortfolioUserStream {
+    /// Get a listen key for the stream
+    pub async fn start(&self) -> Result<UserDataStream> {
+        let data = self.client.post(PORTFOLIO_USER_DATA_STREAM).await?;
+        let user_data_stream: UserDataStream = from_str(data.as_str())?;
+
+        Ok(user_data_stream)
+    }
+
+    /// Keep the connection alive, as the listen key becomes invalid after 60mn
+    pub async fn keep_alive(&self, listen_key: &str) -> Result<Success> {
+        let data = self
+            .client
+            .put(PORTFOLIO_USER_DATA_STREAM, listen_key)
+            .await?;
+
+        let success: Success = from_str(data.as_str())?;
+
+        Ok(success)
+    }
+
+    /// Invalidate the listen key
+    pub async fn close(&self, listen_key: &str) -> Result<Success> {
+        let data = self
+            .client
+            .delete(PORTFOLIO_USER_DATA_STREAM, listen_key)
+            .await?;
+
+        let success: Success = from_str(data.as_str())?;
+
+        Ok(success)
+    }
+}
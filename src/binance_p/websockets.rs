@@ -0,0 +1,48 @@
+use super::config::*;
+use super::errors::*;
+use crate::binance_f::websockets::FuturesWebSockets;
+
+use actix_codec::Framed;
+use awc::{ws::Codec, BoxedSocket, ClientResponse};
+use local_channel::mpsc;
+use std::sync::atomic::AtomicBool;
+
+pub use crate::binance_f::websockets::WS_ENDPOINT;
+
+/// Connector to the portfolio margin user data stream, the futures one on the portfolio margin
+/// websocket endpoint
+pub struct PortfolioWebSockets<WE: serde::de::DeserializeOwned + std::fmt::Debug> {
+    inner: FuturesWebSockets<WE>,
+}
+
+impl<WE: serde::de::DeserializeOwned + std::fmt::Debug> PortfolioWebSockets<WE> {
+    /// New websocket holder with default configuration
+    pub fn new(sender: mpsc::Sender<WE>) -> PortfolioWebSockets<WE> {
+        Self::new_with_options(sender, Config::default())
+    }
+
+    /// New websocket holder with provided configuration
+    pub fn new_with_options(sender: mpsc::Sender<WE>, conf: Config) -> PortfolioWebSockets<WE> {
+        PortfolioWebSockets {
+            inner: FuturesWebSockets::new_with_endpoint(sender, conf.portfolio_ws_endpoint),
+        }
+    }
+
+    /// Connect to the user data stream of a listen key
+    pub async fn connect(&mut self, listen_key: &str) -> Result<()> {
+        self.inner.connect(listen_key).await
+    }
+
+    /// Disconnect from the endpoint
+    pub async fn disconnect(&mut self) -> Result<()> {
+        self.inner.disconnect().await
+    }
+
+    pub fn socket(&self) -> &Option<(ClientResponse, Framed<BoxedSocket, Codec>)> {
+        self.inner.socket()
+    }
+
+    pub async fn event_loop(&mut self, running: &AtomicBool) -> Result<()> {
+        self.inner.event_loop(running).await
+    }
+}
@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+
+use super::rest_model::{string_or_float, string_or_float_opt};
+pub use crate::binance::ws_model::{AccountPositionUpdate, BalanceUpdate, OrderUpdate};
+pub use crate::binance_f::ws_model::ListenKeyExpiredEvent;
+
+/// Events of the portfolio margin user data stream, futures events carry the business unit they
+/// come from, cross margin events are those of the spot user data stream
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "e")]
+pub enum PortfolioWebsocketEvent {
+    #[serde(alias = "ORDER_TRADE_UPDATE")]
+    OrderTradeUpdate(Box<OrderTradeUpdateEvent>),
+    #[serde(alias = "ACCOUNT_UPDATE")]
+    AccountUpdate(AccountUpdateEvent),
+    #[serde(alias = "riskLevelChange")]
+    RiskLevelChange(RiskLevelChangeEvent),
+    #[serde(alias = "liabilityChange")]
+    LiabilityChange(LiabilityChangeEvent),
+    #[serde(alias = "openOrderLoss")]
+    OpenOrderLoss(OpenOrderLossEvent),
+
+    #[serde(alias = "executionReport")]
+    MarginOrderUpdate(Box<OrderUpdate>),
+    #[serde(alias = "outboundAccountPosition")]
+    MarginAccountPositionUpdate(AccountPositionUpdate),
+    #[serde(alias = "balanceUpdate")]
+    MarginBalanceUpdate(BalanceUpdate),
+
+    /// The listen key of the user data stream expired, a new one must be requested
+    #[serde(alias = "listenKeyExpired")]
+    ListenKeyExpired(ListenKeyExpiredEvent),
+}
+
+/// Futures market of a portfolio margin event
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+pub enum BusinessUnit {
+    /// USD-M futures
+    #[serde(rename = "UM")]
+    UsdMargined,
+    /// COIN-M futures
+    #[serde(rename = "CM")]
+    CoinMargined,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeUpdateEvent {
+    #[serde(rename = "fs")]
+    pub business_unit: BusinessUnit,
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "o")]
+    pub order_trade_update: OrderTradeUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OrderTradeUpdate {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "c")]
+    pub client_order_id: String,
+    #[serde(rename = "S")]
+    pub side: String,
+    #[serde(rename = "o")]
+    pub order_type: String,
+    #[serde(rename = "f")]
+    pub time_in_force: String,
+    /// In contracts on COIN-M
+    #[serde(rename = "q", with = "string_or_float")]
+    pub original_quantity: f64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub original_price: f64,
+    #[serde(rename = "ap", with = "string_or_float")]
+    pub average_price: f64,
+    #[serde(rename = "x")]
+    pub execution_type: String,
+    #[serde(rename = "X")]
+    pub order_status: String,
+    #[serde(rename = "i")]
+    pub order_id: u64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub order_last_filled_quantity: f64,
+    #[serde(rename = "z", with = "string_or_float")]
+    pub order_filled_accumulated_quantity: f64,
+    #[serde(rename = "L", with = "string_or_float")]
+    pub last_filled_price: f64,
+    /// Only sent with trades
+    #[serde(rename = "N", default)]
+    pub commission_asset: Option<String>,
+    #[serde(rename = "n", default, with = "string_or_float_opt")]
+    pub commission: Option<f64>,
+    #[serde(rename = "T")]
+    pub order_trade_time: u64,
+    #[serde(rename = "t")]
+    pub trade_id: u64,
+    #[serde(rename = "m")]
+    pub is_maker: bool,
+    #[serde(rename = "R")]
+    pub reduce_only: bool,
+    #[serde(rename = "ps")]
+    pub position_side: String,
+    #[serde(rename = "rp", with = "string_or_float")]
+    pub realized_profit: f64,
+    /// Only sent on COIN-M
+    #[serde(rename = "ma", default)]
+    pub margin_asset: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdateEvent {
+    #[serde(rename = "fs")]
+    pub business_unit: BusinessUnit,
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "T")]
+    pub transaction_time: u64,
+    #[serde(rename = "a")]
+    pub account_update: AccountUpdate,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct AccountUpdate {
+    #[serde(rename = "m")]
+    pub event_reason_type: String,
+    #[serde(rename = "B")]
+    pub balances: Vec<Balance>,
+    #[serde(rename = "P")]
+    pub positions: Vec<Position>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Balance {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "wb", with = "string_or_float")]
+    pub wallet_balance: f64,
+    #[serde(rename = "cw", with = "string_or_float")]
+    pub cross_wallet_balance: f64,
+    #[serde(rename = "bc", with = "string_or_float")]
+    pub balance_change: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Position {
+    #[serde(rename = "s")]
+    pub symbol: String,
+    #[serde(rename = "pa", with = "string_or_float")]
+    pub position_amount: f64,
+    #[serde(rename = "ep", with = "string_or_float")]
+    pub entry_price: f64,
+    #[serde(rename = "cr", with = "string_or_float")]
+    pub accumulated_realized: f64,
+    #[serde(rename = "up", with = "string_or_float")]
+    pub unrealized_pnl: f64,
+    #[serde(rename = "ps")]
+    pub position_side: String,
+}
+
+/// The unified maintenance margin ratio crossed a risk level
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct RiskLevelChangeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    /// Unified maintenance margin ratio
+    #[serde(rename = "u", with = "string_or_float")]
+    pub uni_mmr: f64,
+    /// MARGIN_CALL, SUPPLY_MARGIN, REDUCE_ONLY, FORCE_LIQUIDATION
+    #[serde(rename = "s")]
+    pub risk_level: String,
+    #[serde(rename = "eq", with = "string_or_float")]
+    pub account_equity: f64,
+    #[serde(rename = "ae", with = "string_or_float")]
+    pub actual_equity: f64,
+    #[serde(rename = "m", with = "string_or_float")]
+    pub maintenance_margin: f64,
+}
+
+/// A cross margin liability was borrowed or repaid
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct LiabilityChangeEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "a")]
+    pub asset: String,
+    /// BORROW, REPAY, ...
+    #[serde(rename = "t")]
+    pub liability_type: String,
+    #[serde(rename = "T")]
+    pub transaction_id: u64,
+    #[serde(rename = "p", with = "string_or_float")]
+    pub principal: f64,
+    #[serde(rename = "i", with = "string_or_float")]
+    pub interest: f64,
+    #[serde(rename = "l", with = "string_or_float")]
+    pub total_liability: f64,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OpenOrderLossEvent {
+    #[serde(rename = "E")]
+    pub event_time: u64,
+    #[serde(rename = "O")]
+    pub losses: Vec<OpenOrderLoss>,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct OpenOrderLoss {
+    #[serde(rename = "a")]
+    pub asset: String,
+    #[serde(rename = "o", with = "string_or_float")]
+    pub amount: f64,
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn decodes_unified_user_stream_events() {
+        let update = r#"{"e":"ORDER_TRADE_UPDATE","fs":"CM","E":1568879465651,
+            "T":1568879465650,"i":"SfsR","o":{"s":"BTCUSD_PERP","c":"TEST","S":"SELL",
+            "o":"LIMIT","f":"GTC","q":"2","p":"30000","ap":"0","sp":"0","x":"NEW","X":"NEW",
+            "i":8886774,"l":"0","z":"0","L":"0","ma":"BTC","N":"BTC","n":"0",
+            "T":1568879465651,"t":0,"b":"0","a":"0","m":false,"R":false,"ps":"BOTH",
+            "rp":"0"}}"#;
+        let event: PortfolioWebsocketEvent = serde_json::from_str(update).unwrap();
+        let PortfolioWebsocketEvent::OrderTradeUpdate(event) = event else {
+            panic!("unexpected event {:?}", event);
+        };
+        assert_eq!(event.business_unit, BusinessUnit::CoinMargined);
+        assert_eq!(
+            event.order_trade_update.margin_asset.as_deref(),
+            Some("BTC")
+        );
+
+        let risk = r#"{"e":"riskLevelChange","E":1587727187525,"u":"1.99999999",
+            "s":"MARGIN_CALL","eq":"30.23416728","ae":"30.23416728","m":"15.11708371"}"#;
+        let event: PortfolioWebsocketEvent = serde_json::from_str(risk).unwrap();
+        assert!(matches!(event, PortfolioWebsocketEvent::RiskLevelChange(_)));
+
+        let liability = r#"{"e":"liabilityChange","E":1573200697110,"a":"BTC",
+            "t":"BORROW","T":1352286576452864727,"p":"1.03453464","i":"0",
+            "l":"1.03476769"}"#;
+        let event: PortfolioWebsocketEvent = serde_json::from_str(liability).unwrap();
+        assert!(matches!(event, PortfolioWebsocketEvent::LiabilityChange(_)));
+    }
+}
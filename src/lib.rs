@@ -5,5 +5,8 @@ extern crate serde;
 extern crate serde_qs as qs;
 
 pub mod binance;
+pub mod binance_d;
 pub mod binance_f;
+pub mod binance_o;
+pub mod binance_p;
 pub mod okex_v5;